@@ -29,3 +29,6 @@ pub mod precomputed_straus;
 
 #[cfg(feature = "alloc")]
 pub mod pippenger;
+
+#[cfg(feature = "alloc")]
+pub mod vartime_batch;
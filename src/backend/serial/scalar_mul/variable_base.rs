@@ -6,10 +6,26 @@ use edwards::EdwardsPoint;
 use backend::serial::curve_models::ProjectiveNielsPoint;
 use window::LookupTable;
 
+#[cfg(feature = "alloc")]
+use prelude::*;
+
 /// Perform constant-time, variable-base scalar multiplication.
 pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
     // Construct a lookup table of [P,2P,3P,4P,5P,6P,7P,8P]
     let lookup_table = LookupTable::<ProjectiveNielsPoint>::from(point);
+    mul_with_table(&lookup_table, scalar)
+}
+
+/// Perform constant-time, variable-base scalar multiplication of the
+/// same point by each of `scalars`, building its lookup table once.
+#[cfg(feature = "alloc")]
+pub(crate) fn mul_each(point: &EdwardsPoint, scalars: &[Scalar]) -> Vec<EdwardsPoint> {
+    let lookup_table = LookupTable::<ProjectiveNielsPoint>::from(point);
+    scalars.iter().map(|s| mul_with_table(&lookup_table, s)).collect()
+}
+
+/// Compute s*P, given the lookup table [P,2P,3P,4P,5P,6P,7P,8P].
+fn mul_with_table(lookup_table: &LookupTable<ProjectiveNielsPoint>, scalar: &Scalar) -> EdwardsPoint {
     // Setting s = scalar, compute
     //
     //    s = s_0 + s_1*16^1 + ... + s_63*16^63,
@@ -0,0 +1,67 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// Copyright (c) 2016-2021 isis lovecruft
+// Copyright (c) 2016-2019 Henry de Valence
+// See LICENSE for licensing information.
+//
+// Authors:
+// - isis agora lovecruft <isis@patternsinthevoid.net>
+// - Henry de Valence <hdevalence@hdevalence.ca>
+
+//! Variable-time computation of a batch of independent scalar products.
+
+#![allow(non_snake_case)]
+
+use traits::Identity;
+use scalar::Scalar;
+use edwards::EdwardsPoint;
+use backend::serial::curve_models::{ProjectiveNielsPoint, ProjectivePoint};
+use window::NafLookupTable5;
+
+#[allow(unused_imports)]
+use prelude::*;
+
+/// Compute \\(s\_1 P\_1, \ldots, s\_n P\_n\\) in variable time.
+///
+/// Unlike a multiscalar multiplication, the products are not summed,
+/// so no doublings can be shared between them.  Instead, all of the
+/// accumulators are stepped through the width-\\(5\\) NAF digits in
+/// lockstep, starting from the highest nonzero digit of any scalar,
+/// so that the inner loop applies the same operation to each entry of
+/// the batch.
+pub fn mul<'a, I>(pairs: I) -> Vec<EdwardsPoint>
+where
+    I: IntoIterator<Item = (&'a Scalar, &'a EdwardsPoint)>,
+{
+    let (nafs, lookup_tables): (Vec<_>, Vec<_>) = pairs
+        .into_iter()
+        .map(|(s, P)| (s.non_adjacent_form(5), NafLookupTable5::<ProjectiveNielsPoint>::from(P)))
+        .unzip();
+
+    let mut acc = vec![ProjectivePoint::identity(); nafs.len()];
+
+    // Find starting index
+    let start = (0..256)
+        .rev()
+        .find(|&i| nafs.iter().any(|naf| naf[i] != 0));
+
+    if let Some(start) = start {
+        for i in (0..=start).rev() {
+            let it = acc.iter_mut().zip(nafs.iter()).zip(lookup_tables.iter());
+            for ((r, naf), lookup_table) in it {
+                let mut t = r.double();
+
+                if naf[i] > 0 {
+                    t = &t.to_extended() + &lookup_table.select(naf[i] as usize);
+                } else if naf[i] < 0 {
+                    t = &t.to_extended() - &lookup_table.select(-naf[i] as usize);
+                }
+
+                *r = t.to_projective();
+            }
+        }
+    }
+
+    acc.iter().map(|r| r.to_extended()).collect()
+}
@@ -164,6 +164,12 @@ impl Scalar29 {
 
     /// Compute `a + b` (mod l).
     pub fn add(a: &Scalar29, b: &Scalar29) -> Scalar29 {
+        Scalar29::add_with_carry(a, b).0
+    }
+
+    /// Compute `a + b` (mod l), also returning `1` if the sum was
+    /// reduced, i.e. if `a + b >= l`, and `0` otherwise.
+    pub fn add_with_carry(a: &Scalar29, b: &Scalar29) -> (Scalar29, u8) {
         let mut sum = Scalar29::zero();
         let mask = (1u32 << 29) - 1;
 
@@ -175,11 +181,18 @@ impl Scalar29 {
         }
 
         // subtract l if the sum is >= l
-        Scalar29::sub(&sum, &constants::L)
+        let (reduced, borrow) = Scalar29::sub_with_borrow(&sum, &constants::L);
+        (reduced, borrow ^ 1)
     }
 
     /// Compute `a - b` (mod l).
     pub fn sub(a: &Scalar29, b: &Scalar29) -> Scalar29 {
+        Scalar29::sub_with_borrow(a, b).0
+    }
+
+    /// Compute `a - b` (mod l), also returning `1` if the difference
+    /// wrapped, i.e. if `a < b`, and `0` otherwise.
+    pub fn sub_with_borrow(a: &Scalar29, b: &Scalar29) -> (Scalar29, u8) {
         let mut difference = Scalar29::zero();
         let mask = (1u32 << 29) - 1;
 
@@ -198,7 +211,7 @@ impl Scalar29 {
             difference[i] = carry & mask;
         }
 
-        difference
+        (difference, (borrow >> 31) as u8)
     }
 
     /// Compute `a * b`.
@@ -367,6 +380,19 @@ impl Scalar29 {
         Scalar29::montgomery_reduce(&Scalar29::square_internal(self))
     }
 
+    /// Square `self` in Montgomery form `squarings` times, then
+    /// Montgomery-multiply the result by `x`.
+    ///
+    /// This is the building block of addition-chain exponentiation;
+    /// both `self` and `x` are in Montgomery form.
+    #[inline]
+    pub fn square_multiply(&mut self, squarings: usize, x: &Scalar29) {
+        for _ in 0..squarings {
+            *self = self.montgomery_square();
+        }
+        *self = Scalar29::montgomery_mul(self, x);
+    }
+
     /// Puts a Scalar29 in to Montgomery form, i.e. computes `a*R (mod l)`
     #[inline(never)]
     pub fn to_montgomery(&self) -> Scalar29 {
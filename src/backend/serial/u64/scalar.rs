@@ -154,6 +154,12 @@ impl Scalar52 {
 
     /// Compute `a + b` (mod l)
     pub fn add(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        Scalar52::add_with_carry(a, b).0
+    }
+
+    /// Compute `a + b` (mod l), also returning `1` if the sum was
+    /// reduced, i.e. if `a + b >= l`, and `0` otherwise
+    pub fn add_with_carry(a: &Scalar52, b: &Scalar52) -> (Scalar52, u8) {
         let mut sum = Scalar52::zero();
         let mask = (1u64 << 52) - 1;
 
@@ -165,11 +171,18 @@ impl Scalar52 {
         }
 
         // subtract l if the sum is >= l
-        Scalar52::sub(&sum, &constants::L)
+        let (reduced, borrow) = Scalar52::sub_with_borrow(&sum, &constants::L);
+        (reduced, borrow ^ 1)
     }
 
     /// Compute `a - b` (mod l)
     pub fn sub(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        Scalar52::sub_with_borrow(a, b).0
+    }
+
+    /// Compute `a - b` (mod l), also returning `1` if the difference
+    /// wrapped, i.e. if `a < b`, and `0` otherwise
+    pub fn sub_with_borrow(a: &Scalar52, b: &Scalar52) -> (Scalar52, u8) {
         let mut difference = Scalar52::zero();
         let mask = (1u64 << 52) - 1;
 
@@ -188,7 +201,7 @@ impl Scalar52 {
             difference[i] = carry & mask;
         }
 
-        difference
+        (difference, (borrow >> 63) as u8)
     }
 
     /// Compute `a * b`
@@ -296,6 +309,19 @@ impl Scalar52 {
         Scalar52::montgomery_reduce(&Scalar52::square_internal(self))
     }
 
+    /// Square `self` in Montgomery form `squarings` times, then
+    /// Montgomery-multiply the result by `x`.
+    ///
+    /// This is the building block of addition-chain exponentiation;
+    /// both `self` and `x` are in Montgomery form.
+    #[inline]
+    pub fn square_multiply(&mut self, squarings: usize, x: &Scalar52) {
+        for _ in 0..squarings {
+            *self = self.montgomery_square();
+        }
+        *self = Scalar52::montgomery_mul(self, x);
+    }
+
     /// Puts a Scalar52 in to Montgomery form, i.e. computes `a*R (mod l)`
     #[inline(never)]
     pub fn to_montgomery(&self) -> Scalar52 {
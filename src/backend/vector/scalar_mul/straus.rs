@@ -89,20 +89,108 @@ impl VartimeMultiscalarMul for Straus {
             .map(|P_opt| P_opt.map(|P| NafLookupTable5::<CachedPoint>::from(&P)))
             .collect::<Option<Vec<_>>>()?;
 
-        let mut Q = ExtendedPoint::identity();
-
-        for i in (0..256).rev() {
-            Q = Q.double();
+        Some(vartime_naf_mul(&nafs, &lookup_tables))
+    }
+}
 
-            for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
-                if naf[i] > 0 {
-                    Q = &Q + &lookup_table.select(naf[i] as usize);
-                } else if naf[i] < 0 {
-                    Q = &Q - &lookup_table.select(-naf[i] as usize);
+impl Straus {
+    /// Variable-time Straus for scalars with an explicit sign, computing
+    /// \\( \pm c\_1 P\_1 + \cdots + \pm c\_n P\_n \\) from pairs
+    /// `(is_negative, c_i)`.
+    ///
+    /// See the serial backend for details.
+    pub fn vartime_multiscalar_mul_signed<I, J>(signed_scalars: I, points: J) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(bool, Scalar)>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        let nafs: Vec<_> = signed_scalars
+            .into_iter()
+            .map(|c| {
+                let (is_negative, ref c) = *c.borrow();
+                let mut naf = c.non_adjacent_form(5);
+                if is_negative {
+                    for digit in naf.iter_mut() {
+                        *digit = -*digit;
+                    }
                 }
+                naf
+            })
+            .collect();
+
+        let lookup_tables: Vec<_> = points
+            .into_iter()
+            .map(|P| NafLookupTable5::<CachedPoint>::from(P.borrow()))
+            .collect();
+
+        assert_eq!(nafs.len(), lookup_tables.len());
+
+        vartime_naf_mul(&nafs, &lookup_tables)
+    }
+}
+
+/// Reusable scratch buffers for
+/// `Straus::vartime_multiscalar_mul_with_workspace`.
+#[derive(Default)]
+pub struct StrausWorkspace {
+    nafs: Vec<[i8; 256]>,
+    lookup_tables: Vec<NafLookupTable5<CachedPoint>>,
+}
+
+impl Straus {
+    /// Variable-time Straus, building the NAF digits and lookup
+    /// tables in the buffers of `workspace`.
+    ///
+    /// See the serial backend for details.
+    pub fn vartime_multiscalar_mul_with_workspace<I, J>(
+        workspace: &mut StrausWorkspace,
+        scalars: I,
+        points: J,
+    ) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        workspace.nafs.clear();
+        workspace.nafs.extend(scalars.into_iter().map(|c| c.borrow().non_adjacent_form(5)));
+
+        workspace.lookup_tables.clear();
+        workspace.lookup_tables.extend(
+            points
+                .into_iter()
+                .map(|P| NafLookupTable5::<CachedPoint>::from(P.borrow())),
+        );
+
+        assert_eq!(workspace.nafs.len(), workspace.lookup_tables.len());
+
+        vartime_naf_mul(&workspace.nafs, &workspace.lookup_tables)
+    }
+}
+
+/// Compute \\( \sum\_i a\_i P\_i \\) in variable time, given the
+/// width-\\(5\\) NAF digits of each \\(a\_i\\) and a table of odd
+/// multiples of each \\(P\_i\\).
+fn vartime_naf_mul(
+    nafs: &[[i8; 256]],
+    lookup_tables: &[NafLookupTable5<CachedPoint>],
+) -> EdwardsPoint {
+    let mut Q = ExtendedPoint::identity();
+
+    for i in (0..256).rev() {
+        Q = Q.double();
+
+        for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
+            if naf[i] > 0 {
+                Q = &Q + &lookup_table.select(naf[i] as usize);
+            } else if naf[i] < 0 {
+                Q = &Q - &lookup_table.select(-naf[i] as usize);
             }
         }
-
-        Some(Q.into())
     }
+
+    Q.into()
 }
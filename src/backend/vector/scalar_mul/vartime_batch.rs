@@ -0,0 +1,61 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// Copyright (c) 2016-2021 isis lovecruft
+// Copyright (c) 2016-2019 Henry de Valence
+// See LICENSE for licensing information.
+//
+// Authors:
+// - isis agora lovecruft <isis@patternsinthevoid.net>
+// - Henry de Valence <hdevalence@hdevalence.ca>
+
+#![allow(non_snake_case)]
+
+use backend::vector::{CachedPoint, ExtendedPoint};
+use edwards::EdwardsPoint;
+use scalar::Scalar;
+use traits::Identity;
+use window::NafLookupTable5;
+
+#[allow(unused_imports)]
+use prelude::*;
+
+/// Compute \\(s\_1 P\_1, \ldots, s\_n P\_n\\) in variable time.
+///
+/// See the `vartime_batch` module in the serial backend for details.
+/// This version keeps the accumulators in the vectorized
+/// `ExtendedPoint` representation, so each doubling and addition uses
+/// the parallel formulas.
+pub fn mul<'a, I>(pairs: I) -> Vec<EdwardsPoint>
+where
+    I: IntoIterator<Item = (&'a Scalar, &'a EdwardsPoint)>,
+{
+    let (nafs, lookup_tables): (Vec<_>, Vec<_>) = pairs
+        .into_iter()
+        .map(|(s, P)| (s.non_adjacent_form(5), NafLookupTable5::<CachedPoint>::from(P)))
+        .unzip();
+
+    let mut acc = vec![ExtendedPoint::identity(); nafs.len()];
+
+    // Find starting index
+    let start = (0..256)
+        .rev()
+        .find(|&i| nafs.iter().any(|naf| naf[i] != 0));
+
+    if let Some(start) = start {
+        for i in (0..=start).rev() {
+            let it = acc.iter_mut().zip(nafs.iter()).zip(lookup_tables.iter());
+            for ((Q, naf), lookup_table) in it {
+                *Q = Q.double();
+
+                if naf[i] > 0 {
+                    *Q = &*Q + &lookup_table.select(naf[i] as usize);
+                } else if naf[i] < 0 {
+                    *Q = &*Q - &lookup_table.select(-naf[i] as usize);
+                }
+            }
+        }
+    }
+
+    acc.into_iter().map(|Q| Q.into()).collect()
+}
@@ -75,29 +75,70 @@ pub const RISTRETTO_BASEPOINT_COMPRESSED: CompressedRistretto =
 /// provides fast scalar multiplication.
 pub const RISTRETTO_BASEPOINT_POINT: RistrettoPoint = RistrettoPoint(ED25519_BASEPOINT_POINT);
 
+/// `L_BYTES` is the little-endian encoding of the order \\( \ell \\) of the
+/// Ristretto group, as a raw byte array.
+pub const L_BYTES: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58,
+    0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
 /// `BASEPOINT_ORDER` is the order of the Ristretto group and of the Ed25519 basepoint, i.e.,
 /// $$
 /// \ell = 2^\{252\} + 27742317777372353535851937790883648493.
 /// $$
-pub const BASEPOINT_ORDER: Scalar = Scalar{
+///
+/// Since it is not reduced mod \\( \ell \\), it reduces to zero.
+pub const BASEPOINT_ORDER: Scalar = Scalar{ bytes: L_BYTES };
+
+/// `BASEPOINT_ORDER_MINUS_1` is \\( \ell - 1 \\), the largest canonical scalar,
+/// which is also \\( -1 \pmod \ell \\).
+pub const BASEPOINT_ORDER_MINUS_1: Scalar = Scalar{
     bytes: [
-        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58,
+        0xec, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58,
         0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
     ],
 };
 
-use ristretto::RistrettoBasepointTable;
+/// `TWO_INV` is \\( 2^{-1} \pmod \ell \\), i.e. \\( (\ell + 1) / 2 \\).
+pub const TWO_INV: Scalar = Scalar{
+    bytes: [
+        0xf7, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c,
+        0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c, 0x6f, 0x0a,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    ],
+};
+
+use ristretto::{BasepointTableWindow, RistrettoBasepointTable};
 /// The Ristretto basepoint, as a `RistrettoBasepointTable` for scalar multiplication.
 pub const RISTRETTO_BASEPOINT_TABLE: RistrettoBasepointTable
-    = RistrettoBasepointTable(ED25519_BASEPOINT_TABLE);
+    = RistrettoBasepointTable(BasepointTableWindow::Radix16(ED25519_BASEPOINT_TABLE));
 
 #[cfg(test)]
 mod test {
     use field::FieldElement;
     use traits::{IsIdentity, ValidityCheck};
     use constants;
+    use scalar::Scalar;
+
+    #[test]
+    fn basepoint_order_reduces_to_zero() {
+        assert_eq!(constants::BASEPOINT_ORDER.to_bytes(), constants::L_BYTES);
+        assert_eq!(constants::BASEPOINT_ORDER.reduce(), Scalar::zero());
+        assert!(!constants::BASEPOINT_ORDER.is_canonical());
+        assert_eq!(constants::BASEPOINT_ORDER_MINUS_1 + Scalar::one(), Scalar::zero());
+    }
+
+    #[test]
+    fn two_inv() {
+        assert!(constants::TWO_INV.is_canonical());
+        assert_eq!(constants::TWO_INV, Scalar::from(2u64).invert());
+        assert_eq!(constants::TWO_INV, Scalar::half_order() + Scalar::one());
+    }
 
     #[test]
     fn test_eight_torsion() {
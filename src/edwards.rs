@@ -744,14 +744,84 @@ impl VartimeMultiscalarMul for EdwardsPoint {
         // Use this as the hint to decide which algorithm to use.
         let size = s_lo;
 
-        if size < 190 {
-            scalar_mul::straus::Straus::optional_multiscalar_mul(scalars, points)
+        let algo = if size < 190 {
+            MultiscalarAlgo::Straus
         } else {
-            scalar_mul::pippenger::Pippenger::optional_multiscalar_mul(scalars, points)
+            MultiscalarAlgo::Pippenger
+        };
+        algo.optional_multiscalar_mul(scalars, points)
+    }
+}
+
+/// A variable-time multiscalar multiplication algorithm.
+///
+/// `VartimeMultiscalarMul` chooses between these based on the number of
+/// inputs; `EdwardsPoint::vartime_multiscalar_mul_with_algo` and
+/// `RistrettoPoint::vartime_multiscalar_mul_with_algo` use the given one.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiscalarAlgo {
+    /// Straus's method, which is faster for small inputs.
+    Straus,
+    /// Pippenger's bucket method, which is faster for large inputs.
+    Pippenger,
+}
+
+#[cfg(feature = "alloc")]
+impl MultiscalarAlgo {
+    fn optional_multiscalar_mul<I, J>(self, scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        match self {
+            MultiscalarAlgo::Straus => {
+                scalar_mul::straus::Straus::optional_multiscalar_mul(scalars, points)
+            }
+            MultiscalarAlgo::Pippenger => {
+                scalar_mul::pippenger::Pippenger::optional_multiscalar_mul(scalars, points)
+            }
         }
     }
 }
 
+impl EdwardsPoint {
+    /// Compute \\(c\_1 P\_1 + \cdots + c\_n P\_n\\) in variable time,
+    /// using the given algorithm rather than choosing one by input size.
+    ///
+    /// The result is the same as `vartime_multiscalar_mul`.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` do not have the same length.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_with_algo<I, J>(
+        algo: MultiscalarAlgo,
+        scalars: I,
+        points: J,
+    ) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        let mut scalars = scalars.into_iter();
+        let mut points = points.into_iter();
+
+        let (s_lo, s_hi) = scalars.by_ref().size_hint();
+        let (p_lo, p_hi) = points.by_ref().size_hint();
+
+        assert_eq!(s_lo, p_lo);
+        assert_eq!(s_hi, Some(s_lo));
+        assert_eq!(p_hi, Some(p_lo));
+
+        algo.optional_multiscalar_mul(scalars, points.map(|P| Some(*P.borrow())))
+            .unwrap()
+    }
+}
+
 /// Precomputation for variable-time multiscalar multiplication with `EdwardsPoint`s.
 // This wraps the inner implementation in a facade type so that we can
 // decouple stability of the inner type from the stability of the
@@ -0,0 +1,51 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// Copyright (c) 2016-2021 isis lovecruft
+// Copyright (c) 2016-2019 Henry de Valence
+// See LICENSE for licensing information.
+//
+// Authors:
+// - isis agora lovecruft <isis@patternsinthevoid.net>
+// - Henry de Valence <hdevalence@hdevalence.ca>
+
+//! Hexadecimal encoding of 32-byte encodings, for `to_hex` and `from_hex`.
+
+#[allow(unused_imports)]
+use prelude::*;
+
+/// Encode `bytes` as lowercase hexadecimal, in order.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut s = String::with_capacity(2 * bytes.len());
+    for b in bytes {
+        s.push(DIGITS[(b >> 4) as usize] as char);
+        s.push(DIGITS[(b & 0x0f) as usize] as char);
+    }
+    s
+}
+
+/// Decode exactly 64 hexadecimal characters (of either case) to 32 bytes.
+pub(crate) fn decode_32(s: &str) -> Option<[u8; 32]> {
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let s = s.as_bytes();
+    if s.len() != 64 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 32];
+    for (byte, pair) in bytes.iter_mut().zip(s.chunks(2)) {
+        *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+    }
+    Some(bytes)
+}
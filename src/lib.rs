@@ -274,8 +274,22 @@ extern crate subtle;
 
 #[cfg(all(test, feature = "serde"))]
 extern crate bincode;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_cbor;
+#[cfg(any(test, feature = "sha2"))]
+extern crate sha2;
 #[cfg(feature = "serde")]
 extern crate serde;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+#[cfg(feature = "group")]
+extern crate group_traits as group;
+#[cfg(feature = "group")]
+extern crate rand_core_06;
 
 // Internal macros. Must come first!
 #[macro_use]
@@ -318,3 +332,6 @@ pub(crate) mod prelude;
 
 // Generic code for window lookups
 pub(crate) mod window;
+
+// Hexadecimal encoding for `to_hex` and `from_hex`
+pub(crate) mod hex_encoding;
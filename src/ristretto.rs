@@ -160,35 +160,60 @@
 
 use core::borrow::Borrow;
 use core::fmt::Debug;
+use core::fmt::Display;
 use core::iter::Sum;
+#[cfg(feature = "alloc")]
+use core::iter;
 use core::ops::{Add, Neg, Sub};
 use core::ops::{AddAssign, SubAssign};
 use core::ops::{Mul, MulAssign};
+use core::ops::Deref;
 
 use rand_core::{CryptoRng, RngCore};
 
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+
 use digest::generic_array::typenum::U64;
+use digest::generic_array::typenum::Unsigned;
+use digest::BlockInput;
 use digest::Digest;
 
 use constants;
 use field::FieldElement;
+use hex_encoding;
 
 use subtle::Choice;
 use subtle::ConditionallySelectable;
 use subtle::ConditionallyNegatable;
 use subtle::ConstantTimeEq;
+#[cfg(feature = "group")]
+use subtle::CtOption;
 
 use zeroize::Zeroize;
 
+use edwards::CompressedEdwardsY;
 use edwards::EdwardsBasepointTable;
+#[cfg(feature = "alloc")]
+use edwards::{EdwardsBasepointTableRadix32, EdwardsBasepointTableRadix64};
+#[cfg(feature = "alloc")]
+use edwards::{EdwardsBasepointTableRadix128, EdwardsBasepointTableRadix256};
 use edwards::EdwardsPoint;
+#[cfg(feature = "alloc")]
+use edwards::MultiscalarAlgo;
 
 #[allow(unused_imports)]
 use prelude::*;
 
 use scalar::Scalar;
 
+#[cfg(feature = "alloc")]
+use traits::BasepointTable;
 use traits::Identity;
+use traits::ValidityCheck;
+use window::LookupTable;
 #[cfg(any(feature = "alloc", feature = "std"))]
 use traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 
@@ -211,7 +236,16 @@ use backend::vector::scalar_mul;
 ///
 /// The Ristretto encoding is canonical, so two points are equal if and
 /// only if their encodings are equal.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+///
+/// The derived `PartialEq` implementation compares the encodings with
+/// `==`, which is not constant-time.  When comparing against a secret
+/// value, use the `subtle::ConstantTimeEq` implementation instead.
+///
+/// The derived `PartialOrd` and `Ord` implementations compare the
+/// encodings lexicographically as byte arrays, so that public
+/// encodings can be sorted or kept in a `BTreeSet`.  This order has no
+/// meaning in the group, and is not constant-time.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct CompressedRistretto(pub [u8; 32]);
 
 impl ConstantTimeEq for CompressedRistretto {
@@ -221,6 +255,9 @@ impl ConstantTimeEq for CompressedRistretto {
 }
 
 impl CompressedRistretto {
+    /// The length in bytes of the encoding of a `CompressedRistretto`.
+    pub const ENCODED_SIZE: usize = 32;
+
     /// Copy the bytes of this `CompressedRistretto`.
     pub fn to_bytes(&self) -> [u8; 32] {
         self.0
@@ -231,6 +268,26 @@ impl CompressedRistretto {
         &self.0
     }
 
+    /// Encode this `CompressedRistretto` as 64 lowercase hexadecimal
+    /// characters, in byte order.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        hex_encoding::encode(self.as_bytes())
+    }
+
+    /// Construct a `CompressedRistretto` from 64 hexadecimal characters.
+    ///
+    /// This only checks the length and characters of `s`; use
+    /// `decompress` to check that the result is a valid encoding.
+    ///
+    /// # Return
+    ///
+    /// - `Some(CompressedRistretto)` if `s` consists of exactly 64 hex digits;
+    /// - `None` otherwise.
+    pub fn from_hex(s: &str) -> Option<CompressedRistretto> {
+        hex_encoding::decode_32(s).map(CompressedRistretto)
+    }
+
     /// Construct a `CompressedRistretto` from a slice of bytes.
     ///
     /// # Panics
@@ -305,6 +362,66 @@ impl CompressedRistretto {
             Some(RistrettoPoint(EdwardsPoint{X: x, Y: y, Z: one, T: t}))
         }
     }
+
+    /// Attempt to decompress to a `RistrettoPoint`, additionally
+    /// checking that recompressing the result gives back `self`.
+    ///
+    /// The checks in `decompress` already ensure that only canonical
+    /// encodings are accepted, so this should never reject an encoding
+    /// that `decompress` accepts.  The round-trip check is a cheap
+    /// safeguard against implementation drift or malleability, for
+    /// consensus-critical code.
+    ///
+    /// # Return
+    ///
+    /// - `Some(RistrettoPoint)` if `self` was the canonical encoding of a
+    ///   point, and that point compresses back to `self`;
+    ///
+    /// - `None` otherwise.
+    pub fn decompress_strict(&self) -> Option<RistrettoPoint> {
+        let P = self.decompress()?;
+        if P.compress().as_bytes().ct_eq(self.as_bytes()).unwrap_u8() == 1u8 {
+            Some(P)
+        } else {
+            None
+        }
+    }
+
+    /// Check in constant time whether every encoding in `points` is
+    /// equal to the first.
+    ///
+    /// Every encoding is compared with `ct_eq`, and the results are
+    /// combined without branching, so the position of a mismatch is not
+    /// revealed.  The encodings are compared as bytes and are not
+    /// decompressed.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if all of `points` are equal, or if `points` is empty;
+    /// - `0u8` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::CompressedRistretto;
+    /// use curve25519_dalek::traits::Identity;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+    ///
+    /// assert_eq!(CompressedRistretto::all_equal(&[B, B, B]), 1u8);
+    /// assert_eq!(CompressedRistretto::all_equal(&[B, CompressedRistretto::identity()]), 0u8);
+    /// ```
+    pub fn all_equal(points: &[CompressedRistretto]) -> u8 {
+        let first = match points.first() {
+            Some(first) => first,
+            None => return 1u8,
+        };
+        points[1..]
+            .iter()
+            .fold(Choice::from(1u8), |acc, P| acc & first.ct_eq(P))
+            .unwrap_u8()
+    }
 }
 
 impl Identity for CompressedRistretto {
@@ -319,6 +436,51 @@ impl Default for CompressedRistretto {
     }
 }
 
+// ------------------------------------------------------------------------
+// Arithmetic on compressed points
+// ------------------------------------------------------------------------
+// These decompress their inputs, operate on the resulting
+// `RistrettoPoint`s, and compress the result.  Each operation costs
+// one inverse square root per input and one for the output, so they
+// are only intended for one-off uses; code doing repeated arithmetic
+// should decompress once and work with `RistrettoPoint`s instead.
+
+impl<'a> Neg for &'a CompressedRistretto {
+    type Output = Option<CompressedRistretto>;
+
+    /// Negate a compressed point.
+    ///
+    /// Returns `None` if `self` is not the canonical encoding of a
+    /// point.
+    fn neg(self) -> Option<CompressedRistretto> {
+        self.decompress().map(|P| (-P).compress())
+    }
+}
+
+impl Neg for CompressedRistretto {
+    type Output = Option<CompressedRistretto>;
+
+    fn neg(self) -> Option<CompressedRistretto> {
+        -&self
+    }
+}
+
+impl<'a, 'b> Sub<&'b CompressedRistretto> for &'a CompressedRistretto {
+    type Output = Option<CompressedRistretto>;
+
+    /// Subtract two compressed points.
+    ///
+    /// Returns `None` if either input is not the canonical encoding of
+    /// a point.
+    fn sub(self, other: &'b CompressedRistretto) -> Option<CompressedRistretto> {
+        let P = self.decompress()?;
+        let Q = other.decompress()?;
+        Some((P - Q).compress())
+    }
+}
+
+define_sub_variants!(LHS = CompressedRistretto, RHS = CompressedRistretto, Output = Option<CompressedRistretto>);
+
 // ------------------------------------------------------------------------
 // Serde support
 // ------------------------------------------------------------------------
@@ -326,6 +488,10 @@ impl Default for CompressedRistretto {
 // and decompression internally.  This means that users can create
 // structs containing `RistrettoPoint`s and use Serde's derived
 // serializers to serialize those structures.
+//
+// Both types serialize as a fixed-size tuple of 32 bytes, rather than as
+// a byte string, so formats like `bincode` emit exactly 32 bytes with no
+// length prefix, and no `#[serde(with = ...)]` attribute is needed.
 
 #[cfg(feature = "serde")]
 use serde::{self, Serialize, Deserialize, Serializer, Deserializer};
@@ -384,7 +550,10 @@ impl<'de> Deserialize<'de> for RistrettoPoint {
                 }
                 CompressedRistretto(bytes)
                     .decompress()
-                    .ok_or(serde::de::Error::custom("decompression failed"))
+                    .ok_or_else(|| serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Bytes(&bytes),
+                        &"a canonical encoding of a Ristretto point",
+                    ))
             }
         }
 
@@ -422,6 +591,199 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
     }
 }
 
+/// A fixed-size array of `CompressedRistretto` encodings, such as the
+/// public keys of a group of known size.
+///
+/// With the `serde` feature, this serializes as a single flat string of
+/// `32 * N` bytes, rather than as `N` separately framed encodings.  The
+/// length is checked on deserialization, but, as for
+/// `CompressedRistretto`, the encodings themselves are not.
+/// Serialization requires the `alloc` feature; deserialization does
+/// not.
+///
+/// This requires the `const-generics` feature.
+#[cfg(feature = "const-generics")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CompressedRistrettoArray<const N: usize>(pub [CompressedRistretto; N]);
+
+#[cfg(all(feature = "serde", feature = "const-generics", feature = "alloc"))]
+impl<const N: usize> Serialize for CompressedRistrettoArray<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut bytes = Vec::with_capacity(32 * N);
+        for encoding in self.0.iter() {
+            bytes.extend_from_slice(encoding.as_bytes());
+        }
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "const-generics"))]
+impl<'de, const N: usize> Deserialize<'de> for CompressedRistrettoArray<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        struct CompressedRistrettoArrayVisitor<const N: usize>;
+
+        impl<'de, const N: usize> Visitor<'de> for CompressedRistrettoArrayVisitor<N> {
+            type Value = CompressedRistrettoArray<N>;
+
+            fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(formatter, "{} bytes of data", 32 * N)
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<CompressedRistrettoArray<N>, E>
+                where E: serde::de::Error
+            {
+                if v.len() != 32 * N {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+                let mut array = [CompressedRistretto([0u8; 32]); N];
+                for (encoding, chunk) in array.iter_mut().zip(v.chunks(32)) {
+                    encoding.0.copy_from_slice(chunk);
+                }
+                Ok(CompressedRistrettoArray(array))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<CompressedRistrettoArray<N>, A::Error>
+                where A: serde::de::SeqAccess<'de>
+            {
+                let mut array = [CompressedRistretto([0u8; 32]); N];
+                for i in 0..32 * N {
+                    array[i / 32].0[i % 32] = seq.next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(32 * N + 1, &self));
+                }
+                Ok(CompressedRistrettoArray(array))
+            }
+        }
+
+        deserializer.deserialize_bytes(CompressedRistrettoArrayVisitor::<N>)
+    }
+}
+
+// ------------------------------------------------------------------------
+// rand support
+// ------------------------------------------------------------------------
+
+/// Sample a uniformly random `RistrettoPoint`, as for
+/// `RistrettoPoint::random`.
+///
+/// The `Distribution` API does not require the RNG to be a `CryptoRng`,
+/// so it is the caller's responsibility to supply a cryptographically
+/// secure one if the point must be unpredictable.
+#[cfg(feature = "rand")]
+impl Distribution<RistrettoPoint> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RistrettoPoint {
+        let mut uniform_bytes = [0u8; 64];
+        rng.fill_bytes(&mut uniform_bytes);
+
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Property testing support
+// ------------------------------------------------------------------------
+
+/// Generate uniformly random `RistrettoPoint`s for property tests, by
+/// applying `from_uniform_bytes` to 64 arbitrary bytes.
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for RistrettoPoint {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<
+        proptest::arbitrary::StrategyFor<([u8; 32], [u8; 32])>,
+        fn(([u8; 32], [u8; 32])) -> RistrettoPoint,
+    >;
+
+    fn arbitrary_with(_args: ()) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::arbitrary::any::<([u8; 32], [u8; 32])>().prop_map(|(lo, hi)| {
+            let mut uniform_bytes = [0u8; 64];
+            uniform_bytes[..32].copy_from_slice(&lo);
+            uniform_bytes[32..].copy_from_slice(&hi);
+            RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+        })
+    }
+}
+
+/// Generate uniformly random `RistrettoPoint`s for `quickcheck`, by
+/// applying `from_uniform_bytes` to 64 arbitrary bytes.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for RistrettoPoint {
+    fn arbitrary(g: &mut quickcheck::Gen) -> RistrettoPoint {
+        let mut uniform_bytes = [0u8; 64];
+        for byte in uniform_bytes.iter_mut() {
+            *byte = u8::arbitrary(g);
+        }
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+}
+
+// ------------------------------------------------------------------------
+// `group` trait support
+// ------------------------------------------------------------------------
+// These are implemented without importing the traits, since
+// `group::Group::identity` would otherwise be ambiguous with
+// `Identity::identity` throughout this module.
+
+#[cfg(feature = "group")]
+impl group::Group for RistrettoPoint {
+    type Scalar = Scalar;
+
+    fn random(mut rng: impl rand_core_06::RngCore) -> Self {
+        let mut uniform_bytes = [0u8; 64];
+        rng.fill_bytes(&mut uniform_bytes);
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+
+    fn identity() -> Self {
+        <RistrettoPoint as Identity>::identity()
+    }
+
+    fn generator() -> Self {
+        constants::RISTRETTO_BASEPOINT_POINT
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&<RistrettoPoint as Identity>::identity())
+    }
+
+    fn double(&self) -> Self {
+        self + self
+    }
+}
+
+/// The encoding is the canonical Ristretto encoding, so decoding
+/// rejects exactly the inputs that `CompressedRistretto::decompress`
+/// rejects.
+#[cfg(feature = "group")]
+impl group::GroupEncoding for RistrettoPoint {
+    type Repr = [u8; 32];
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        let point = CompressedRistretto(*bytes).decompress();
+        let is_valid = Choice::from(point.is_some() as u8);
+        CtOption::new(point.unwrap_or_default(), is_valid)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        // There is no cheaper unchecked decoding for Ristretto.
+        <RistrettoPoint as group::GroupEncoding>::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.compress().to_bytes()
+    }
+}
+
+#[cfg(feature = "group")]
+impl group::prime::PrimeGroup for RistrettoPoint {}
+
 // ------------------------------------------------------------------------
 // Internal point representations
 // ------------------------------------------------------------------------
@@ -443,6 +805,15 @@ pub struct RistrettoPoint(pub(crate) EdwardsPoint);
 impl RistrettoPoint {
     /// Compress this point using the Ristretto encoding.
     pub fn compress(&self) -> CompressedRistretto {
+        let mut bytes = [0u8; 32];
+        self.compress_into(&mut bytes);
+        CompressedRistretto(bytes)
+    }
+
+    /// Compress this point using the Ristretto encoding, writing the
+    /// encoding into `out` rather than returning a new
+    /// `CompressedRistretto`.
+    pub fn compress_into(&self, out: &mut [u8; 32]) {
         let mut X = self.0.X;
         let mut Y = self.0.Y;
         let Z = &self.0.Z;
@@ -474,7 +845,210 @@ impl RistrettoPoint {
         let s_is_negative = s.is_negative();
         s.conditional_negate(s_is_negative);
 
-        CompressedRistretto(s.to_bytes())
+        *out = s.to_bytes();
+    }
+
+    /// Compress this point to its 32-byte canonical encoding.
+    ///
+    /// This is the same as `self.compress().to_bytes()`.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.compress().0
+    }
+
+    /// Attempt to decode a `RistrettoPoint` from its 32-byte canonical
+    /// encoding.
+    ///
+    /// This is the same as `CompressedRistretto(*bytes).decompress()`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(RistrettoPoint)` if `bytes` is the canonical encoding of a point;
+    ///
+    /// - `None` if `bytes` is not the canonical encoding of a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    ///
+    /// assert_eq!(RistrettoPoint::from_bytes(&B.to_bytes()), Some(B));
+    /// ```
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<RistrettoPoint> {
+        CompressedRistretto(*bytes).decompress()
+    }
+
+    /// Compress this point, prefixed with a caller-chosen type tag.
+    ///
+    /// This is `tag` followed by the 32-byte canonical encoding, for
+    /// use in wire formats which carry several kinds of 32-byte
+    /// objects, so that, for instance, a scalar cannot be mistaken for
+    /// a point.  Use `decompress_tagged` with the same tag to decode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let encoded = B.compress_tagged(b'P');
+    ///
+    /// assert_eq!(encoded[0], b'P');
+    /// assert_eq!(RistrettoPoint::decompress_tagged(&encoded, b'P'), Some(B));
+    /// assert_eq!(RistrettoPoint::decompress_tagged(&encoded, b'S'), None);
+    /// ```
+    pub fn compress_tagged(&self, tag: u8) -> [u8; 33] {
+        let mut encoded = [0u8; 33];
+        encoded[0] = tag;
+        encoded[1..].copy_from_slice(self.compress().as_bytes());
+        encoded
+    }
+
+    /// Attempt to decode a `RistrettoPoint` produced by
+    /// `compress_tagged`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(RistrettoPoint)` if the first byte of `bytes` is
+    ///   `expected_tag` and the remaining 32 bytes are the canonical
+    ///   encoding of a point;
+    ///
+    /// - `None` otherwise.  The tag is checked first, so no
+    ///   decompression is attempted if it does not match.
+    pub fn decompress_tagged(bytes: &[u8; 33], expected_tag: u8) -> Option<RistrettoPoint> {
+        if bytes[0] != expected_tag {
+            return None;
+        }
+        CompressedRistretto::from_slice(&bytes[1..]).decompress()
+    }
+
+    /// Compute \\( s \cdot \mathtt{base} - \mathtt{sub} \\), as in
+    /// verification equations of the form \\( sB - A \\).
+    ///
+    /// This runs in constant time.  It is computed as a single
+    /// variable-base scalar multiplication followed by a subtraction;
+    /// treating `sub` as a second multiscalar term with coefficient
+    /// \\( -1 \\) would cost a full scalar multiplication's worth of
+    /// additions rather than one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let s = Scalar::from(3u64);
+    ///
+    /// assert_eq!(RistrettoPoint::mul_sub(&s, &B, &B), B + B);
+    /// ```
+    pub fn mul_sub(s: &Scalar, base: &RistrettoPoint, sub: &RistrettoPoint) -> RistrettoPoint {
+        base * s - sub
+    }
+
+    /// Select `points[index]`.
+    ///
+    /// If `index_is_secret` is `true`, the selection is done in
+    /// constant time: every element of `points` is visited, and the
+    /// result is assigned under a mask rather than by indexing, so the
+    /// memory access pattern does not depend on `index`.  This costs
+    /// \\( O(n) \\) for \\( n \\) points.  In this case an
+    /// out-of-range `index` gives the identity.
+    ///
+    /// If `index_is_secret` is `false`, this simply returns
+    /// `points[index]`.
+    ///
+    /// # Panics
+    ///
+    /// If `index_is_secret` is `false` and `index` is out of range.
+    pub fn conditional_select_from(
+        points: &[RistrettoPoint],
+        index: usize,
+        index_is_secret: bool,
+    ) -> RistrettoPoint {
+        if !index_is_secret {
+            return points[index];
+        }
+
+        let index = index as u64;
+        let mut selected = RistrettoPoint::identity();
+        for (i, P) in points.iter().enumerate() {
+            selected.conditional_assign(P, (i as u64).ct_eq(&index));
+        }
+        selected
+    }
+
+    /// Decompress `c` and add it to this point.
+    ///
+    /// # Return
+    ///
+    /// - `Some(self + P)` if `c` is the canonical encoding of a point `P`;
+    ///
+    /// - `None` if `c` is not the canonical encoding of a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::CompressedRistretto;
+    /// use curve25519_dalek::traits::Identity;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    ///
+    /// assert_eq!(B.add_compressed(&CompressedRistretto::identity()), Some(B));
+    /// ```
+    pub fn add_compressed(&self, c: &CompressedRistretto) -> Option<RistrettoPoint> {
+        c.decompress().map(|P| self + P)
+    }
+
+    /// Check, in constant time, whether `c` is the encoding of this point.
+    ///
+    /// This compresses `self` and compares the encodings with `ct_eq`,
+    /// so a non-canonical `c` never compares equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    ///
+    /// assert!(B.equals_compressed(&constants::RISTRETTO_BASEPOINT_COMPRESSED));
+    /// ```
+    pub fn equals_compressed(&self, c: &CompressedRistretto) -> bool {
+        self.compress().ct_eq(c).into()
+    }
+
+    /// Absorb this point into a Fiat-Shamir transcript hash.
+    ///
+    /// This feeds `hasher` the tag byte `b'P'`, the length of the
+    /// encoding as 8 little-endian bytes, and the 32-byte canonical
+    /// encoding of the point.  `Scalar::absorb_into` uses the tag
+    /// `b'S'`, so points and scalars cannot be confused in a transcript.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # extern crate sha2;
+    /// use curve25519_dalek::constants;
+    /// use sha2::{Digest, Sha512};
+    ///
+    /// # fn main() {
+    /// let mut transcript = Sha512::new();
+    /// constants::RISTRETTO_BASEPOINT_POINT.absorb_into(&mut transcript);
+    /// let challenge = transcript.finalize();
+    /// # }
+    /// ```
+    pub fn absorb_into<D: Digest>(&self, hasher: &mut D) {
+        let encoding = self.compress();
+        hasher.update([b'P']);
+        hasher.update((encoding.0.len() as u64).to_le_bytes());
+        hasher.update(encoding.as_bytes());
     }
 
     /// Double-and-compress a batch of points.  The Ristretto encoding
@@ -584,17 +1158,164 @@ impl RistrettoPoint {
         }).collect()
     }
 
+    /// Encode a batch of points as a little-endian `u32` count,
+    /// followed by the 32-byte compressed encoding of each point.
+    ///
+    /// # Panics
+    ///
+    /// If `points` has more than `u32::max_value()` entries.
+    #[cfg(feature = "alloc")]
+    pub fn encode_batch(points: &[RistrettoPoint]) -> Vec<u8> {
+        use core::convert::TryFrom;
 
-    /// Return the coset self + E[4], for debugging.
-    fn coset4(&self) -> [EdwardsPoint; 4] {
-        [  self.0
-        , &self.0 + &constants::EIGHT_TORSION[2]
-        , &self.0 + &constants::EIGHT_TORSION[4]
-        , &self.0 + &constants::EIGHT_TORSION[6]
-        ]
+        let count = u32::try_from(points.len()).expect("too many points to encode");
+
+        let mut bytes = Vec::with_capacity(4 + 32 * points.len());
+        bytes.extend_from_slice(&count.to_le_bytes());
+        for P in points {
+            bytes.extend_from_slice(P.compress().as_bytes());
+        }
+        bytes
     }
 
-    /// Computes the Ristretto Elligator map.
+    /// Decode a batch of points produced by `encode_batch`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(Vec<RistrettoPoint>)` if the count prefix matches the
+    ///   length of `bytes` and every encoding is a valid point;
+    /// - `None` otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn decode_batch(bytes: &[u8]) -> Option<Vec<RistrettoPoint>> {
+        if bytes.len() < 4 {
+            return None;
+        }
+        let mut count_bytes = [0u8; 4];
+        count_bytes.copy_from_slice(&bytes[..4]);
+        let count = u32::from_le_bytes(count_bytes) as usize;
+
+        let body = &bytes[4..];
+        if count.checked_mul(32) != Some(body.len()) {
+            return None;
+        }
+
+        body.chunks(32)
+            .map(|chunk| CompressedRistretto::from_slice(chunk).decompress())
+            .collect()
+    }
+
+    /// Check that the internal representative of this point lies on
+    /// the curve, with consistent extended coordinates.
+    ///
+    /// Points produced by this crate's API are always valid, so this is
+    /// only useful as a defensive check against in-memory corruption.
+    /// This function is not constant-time.
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// Check that `points` are pairwise distinct, and that none of them
+    /// is the identity.
+    ///
+    /// This is intended for validating independently generated
+    /// generators, for instance those from `from_label`, at setup time
+    /// or in tests.  It compares every pair of points, so it takes
+    /// quadratic time, and it is not constant-time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// extern crate sha2;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let G = RistrettoPoint::hash_from_bytes::<Sha512>(b"G");
+    /// let H = RistrettoPoint::hash_from_bytes::<Sha512>(b"H");
+    ///
+    /// assert!(RistrettoPoint::all_distinct(&[G, H]));
+    /// assert!(!RistrettoPoint::all_distinct(&[G, H, G]));
+    /// # }
+    /// ```
+    pub fn all_distinct(points: &[RistrettoPoint]) -> bool {
+        let identity = RistrettoPoint::identity();
+        for (i, P) in points.iter().enumerate() {
+            if *P == identity || points[i + 1..].contains(P) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Check in constant time whether `a[i] == b[i]` for every `i`.
+    ///
+    /// Every pair is compared, and the results are combined without
+    /// branching, so no information is leaked about which pair (if
+    /// any) differs.  Only the lengths of the slices are public.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if `a` and `b` have the same length and are pairwise equal;
+    /// - `0u8` otherwise.
+    pub fn ct_eq_batch(a: &[RistrettoPoint], b: &[RistrettoPoint]) -> u8 {
+        let same_len = Choice::from((a.len() == b.len()) as u8);
+        a.iter()
+            .zip(b.iter())
+            .fold(same_len, |acc, (A, B)| acc & A.ct_eq(B))
+            .unwrap_u8()
+    }
+
+
+    /// Attempt to construct a `RistrettoPoint` from the Edwards
+    /// encoding of one of its representatives.
+    ///
+    /// Internally, a Ristretto point is a coset \\(P + \mathcal E[4]\\)
+    /// of a point \\(P \in 2\mathcal E\\).  Not every Edwards point is a
+    /// valid representative: this function checks that the decoded
+    /// point lies in \\(2\mathcal E\\) by re-deriving the canonical
+    /// representative through a compression round-trip and checking
+    /// that the input lies in its coset.
+    ///
+    /// Small-order points are rejected.  This includes the points of
+    /// \\(\mathcal E[4]\\), which would otherwise be valid
+    /// representatives of the identity, since an interop boundary
+    /// handing over a small-order point is almost certainly an error.
+    ///
+    /// # Return
+    ///
+    /// - `Some(RistrettoPoint)` if `bytes` decodes to an Edwards point
+    ///   which is not of small order and represents a Ristretto point;
+    ///
+    /// - `None` otherwise.
+    pub fn from_compressed_edwards(bytes: &CompressedEdwardsY) -> Option<RistrettoPoint> {
+        let P = RistrettoPoint(bytes.decompress()?);
+        if P.0.is_small_order() {
+            return None;
+        }
+        let Q = P.compress().decompress()?;
+
+        if Q.coset4().contains(&P.0) {
+            Some(P)
+        } else {
+            None
+        }
+    }
+
+    /// Return the coset self + E[4], the four Edwards points that
+    /// represent the same Ristretto point as `self`.
+    ///
+    /// `from_compressed_edwards` uses this to check that an Edwards
+    /// point is a representative of its Ristretto encoding.
+    fn coset4(&self) -> [EdwardsPoint; 4] {
+        [  self.0
+        , &self.0 + &constants::EIGHT_TORSION[2]
+        , &self.0 + &constants::EIGHT_TORSION[4]
+        , &self.0 + &constants::EIGHT_TORSION[6]
+        ]
+    }
+
+    /// Computes the Ristretto Elligator map.
     ///
     /// # Note
     ///
@@ -658,6 +1379,31 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&uniform_bytes)
     }
 
+    /// Return `n` independent, uniformly random `RistrettoPoint`s.
+    ///
+    /// The points are the same as `n` successive calls to
+    /// `RistrettoPoint::random`, so a seeded `rng` reproduces them.  The
+    /// map to the group involves no inversions, so there is no shared
+    /// work to batch across points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # extern crate rand_core;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let generators = RistrettoPoint::random_batch(&mut OsRng, 16);
+    /// assert_eq!(generators.len(), 16);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn random_batch<R: RngCore + CryptoRng>(rng: &mut R, n: usize) -> Vec<RistrettoPoint> {
+        (0..n).map(|_| RistrettoPoint::random(rng)).collect()
+    }
+
     /// Hash a slice of bytes into a `RistrettoPoint`.
     ///
     /// Takes a type parameter `D`, which is any `Digest` producing 64
@@ -696,13 +1442,78 @@ impl RistrettoPoint {
         RistrettoPoint::from_hash(hash)
     }
 
+    /// Hash a slice of bytes to both a `RistrettoPoint` and a `Scalar`,
+    /// as needed by a VRF.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes of output.
+    ///
+    /// # Implementation
+    ///
+    /// The two outputs come from separate hash invocations with distinct
+    /// domain separation prefixes:
+    ///
+    /// * the point is `from_hash` of
+    ///   \\( H(\texttt{"curve25519-dalek h2p point"} \| \mathtt{input}) \\);
+    /// * the scalar is `Scalar::from_hash` of
+    ///   \\( H(\texttt{"curve25519-dalek h2p scalar"} \| \mathtt{input}) \\).
+    ///
+    /// Neither prefix is a prefix of the other, so the two hash inputs
+    /// never coincide, and modelling \\( H \\) as a random oracle, the
+    /// point and the scalar are independent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// extern crate sha2;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let (H, s) = RistrettoPoint::hash_to_point_and_base_scalar::<Sha512>(b"input");
+    /// # }
+    /// ```
+    pub fn hash_to_point_and_base_scalar<D>(input: &[u8]) -> (RistrettoPoint, Scalar)
+        where D: Digest<OutputSize = U64> + Default
+    {
+        let point_hash = D::default().chain(b"curve25519-dalek h2p point").chain(input);
+        let scalar_hash = D::default().chain(b"curve25519-dalek h2p scalar").chain(input);
+        (RistrettoPoint::from_hash(point_hash), Scalar::from_hash(scalar_hash))
+    }
+
     /// Construct a `RistrettoPoint` from an existing `Digest` instance.
     ///
     /// Use this instead of `hash_from_bytes` if it is more convenient
     /// to stream data into the `Digest` than to pass a single byte
     /// slice.
+    ///
+    /// Since the digest instance is supplied by the caller, `D` need
+    /// not implement `Default`, so keyed or otherwise pre-initialized
+    /// hashers can be used here.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// extern crate sha2;
+    ///
+    /// use sha2::Digest;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let mut hasher = Sha512::new();
+    /// hasher.update(b"To really appreciate architecture, ");
+    /// hasher.update(b"you may even need to commit a murder");
+    /// let P = RistrettoPoint::from_hash(hasher);
+    ///
+    /// let msg = b"To really appreciate architecture, you may even need to commit a murder";
+    /// assert_eq!(P, RistrettoPoint::hash_from_bytes::<Sha512>(msg));
+    /// # }
+    /// ```
     pub fn from_hash<D>(hash: D) -> RistrettoPoint
-        where D: Digest<OutputSize = U64> + Default
+        where D: Digest<OutputSize = U64>
     {
         // dealing with generic arrays is clumsy, until const generics land
         let output = hash.finalize();
@@ -723,6 +1534,10 @@ impl RistrettoPoint {
     /// This function splits the input array into two 32-byte halves,
     /// takes the low 255 bits of each half mod p, applies the
     /// Ristretto-flavored Elligator map to each, and adds the results.
+    ///
+    /// This map is not invertible: many inputs give the same point,
+    /// and the sum of the two Elligator images cannot be split back
+    /// into its halves, so there is no corresponding `to_uniform_bytes`.
     pub fn from_uniform_bytes(bytes: &[u8; 64]) -> RistrettoPoint {
         let mut r_1_bytes = [0u8; 32];
         r_1_bytes.copy_from_slice(&bytes[0..32]);
@@ -738,6 +1553,84 @@ impl RistrettoPoint {
         // uniform distribution.
         &R_1 + &R_2
     }
+
+    /// Map 32 bytes to a `RistrettoPoint` with a single application of
+    /// the Ristretto-flavoured Elligator map.
+    ///
+    /// This is the `MAP` function of the `ristretto255` specification.
+    /// The low 255 bits of `bytes` are interpreted as a field element
+    /// mod p, and the high bit is ignored.
+    ///
+    /// Unlike `from_uniform_bytes`, the output is **not** uniformly
+    /// distributed over the group, even for uniform input: only about
+    /// half of the points are in the image of the map.  To hash to the
+    /// group, use `from_uniform_bytes` or `hash_from_bytes` instead.
+    pub fn from_elligator_single(bytes: &[u8; 32]) -> RistrettoPoint {
+        let r_0 = FieldElement::from_bytes(bytes);
+        RistrettoPoint::elligator_ristretto_flavor(&r_0)
+    }
+
+    /// Hash a message to a `RistrettoPoint`, following the
+    /// `ristretto255_XMD:SHA-512_R255MAP_RO_` suite of the IETF
+    /// hash-to-curve specification (RFC 9380).
+    ///
+    /// The message is expanded to 64 uniform bytes with
+    /// `expand_message_xmd`, using the domain separation tag `dst`, and
+    /// the result is mapped to the group with `from_uniform_bytes`.
+    /// The suite specifies SHA-512; it is supplied by the caller as
+    /// `D`, as for `hash_from_bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// extern crate sha2;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let dst = b"MYAPP-V01-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let P = RistrettoPoint::hash_to_curve::<Sha512>(b"hello", dst);
+    /// assert_eq!(P, RistrettoPoint::hash_to_curve::<Sha512>(b"hello", dst));
+    /// # }
+    /// ```
+    pub fn hash_to_curve<D>(msg: &[u8], dst: &[u8]) -> RistrettoPoint
+        where D: BlockInput + Digest<OutputSize = U64> + Default
+    {
+        let mut uniform_bytes = [0u8; 64];
+        expand_message_xmd::<D>(msg, dst, &mut uniform_bytes);
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+
+    /// Deterministically derive a `RistrettoPoint` from a `label`.
+    ///
+    /// The label is hashed with a fixed domain separator, and the
+    /// 64-byte digest is mapped to the group with `from_uniform_bytes`.
+    /// The same label always gives the same point, on every platform,
+    /// so this is suitable for reproducible test fixtures, or for
+    /// generating independent generators \\(H, G\_1, \ldots, G\_n\\)
+    /// with no known discrete log relation between them.
+    ///
+    /// The hash function is fixed to SHA-512, rather than chosen by the
+    /// caller, so that every user derives the same point from the same
+    /// label.  This requires the `sha2` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    ///
+    /// let H = RistrettoPoint::from_label(b"H");
+    /// assert_eq!(H, RistrettoPoint::from_label(b"H"));
+    /// assert_ne!(H, RistrettoPoint::from_label(b"G_1"));
+    /// ```
+    #[cfg(feature = "sha2")]
+    pub fn from_label(label: &[u8]) -> RistrettoPoint {
+        let mut hash = ::sha2::Sha512::default();
+        hash.update(b"curve25519-dalek RistrettoPoint::from_label");
+        hash.update(label);
+        RistrettoPoint::from_hash(hash)
+    }
 }
 
 impl Identity for RistrettoPoint {
@@ -781,6 +1674,61 @@ impl ConstantTimeEq for RistrettoPoint {
 
 impl Eq for RistrettoPoint {}
 
+/// Fill `out` with `expand_message_xmd(msg, dst, out.len())`, as
+/// specified in section 5.3.1 of RFC 9380.
+///
+/// Tags longer than 255 bytes are first hashed, as described in
+/// section 5.3.3.
+fn expand_message_xmd<D>(msg: &[u8], dst: &[u8], out: &mut [u8])
+    where D: BlockInput + Digest<OutputSize = U64> + Default
+{
+    let b_in_bytes = 64;
+    assert!(out.len() <= 255 * b_in_bytes, "requested too many bytes from expand_message_xmd");
+
+    let long_dst;
+    let dst = if dst.len() > 255 {
+        let mut hash = D::default();
+        hash.update(b"H2C-OVERSIZE-DST-");
+        hash.update(dst);
+        long_dst = hash.finalize();
+        &long_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+    let len_in_bytes = [(out.len() >> 8) as u8, out.len() as u8];
+
+    let mut hash = D::default();
+    for _ in 0..D::BlockSize::to_usize() {
+        hash.update([0u8]);
+    }
+    hash.update(msg);
+    hash.update(len_in_bytes);
+    hash.update([0u8]);
+    hash.update(dst);
+    hash.update(dst_len);
+    let b_0 = hash.finalize();
+
+    let mut b_i = [0u8; 64];
+    for (i, chunk) in out.chunks_mut(b_in_bytes).enumerate() {
+        let mut hash = D::default();
+        if i == 0 {
+            hash.update(b_0);
+        } else {
+            let mut xored = [0u8; 64];
+            for (x, (y, z)) in xored.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+                *x = y ^ z;
+            }
+            hash.update(&xored[..]);
+        }
+        hash.update([(i + 1) as u8]);
+        hash.update(dst);
+        hash.update(dst_len);
+        b_i.copy_from_slice(&hash.finalize());
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
 // ------------------------------------------------------------------------
 // Arithmetic
 // ------------------------------------------------------------------------
@@ -889,6 +1837,16 @@ define_mul_variants!(LHS = Scalar, RHS = RistrettoPoint, Output = RistrettoPoint
 impl MultiscalarMul for RistrettoPoint {
     type Point = RistrettoPoint;
 
+    /// Given an iterator of scalars and an iterator of points, compute
+    /// \\( \sum\_i s\_i P\_i \\) in constant time.
+    ///
+    /// If both iterators are empty, the sum is empty and the result is
+    /// `RistrettoPoint::identity()`.
+    ///
+    /// # Panics
+    ///
+    /// If the iterators do not report the same exact length in their
+    /// size hints; this is checked by the `EdwardsPoint` implementation.
     fn multiscalar_mul<I, J>(scalars: I, points: J) -> RistrettoPoint
     where
         I: IntoIterator,
@@ -965,216 +1923,1837 @@ impl VartimePrecomputedMultiscalarMul for VartimeRistrettoPrecomputation {
     }
 }
 
-impl RistrettoPoint {
-    /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the
-    /// Ristretto basepoint.
-    pub fn vartime_double_scalar_mul_basepoint(
-        a: &Scalar,
-        A: &RistrettoPoint,
-        b: &Scalar,
-    ) -> RistrettoPoint {
-        RistrettoPoint(
-            EdwardsPoint::vartime_double_scalar_mul_basepoint(a, &A.0, b)
-        )
-    }
-}
-
-/// A precomputed table of multiples of a basepoint, used to accelerate
-/// scalar multiplication.
+/// Reusable scratch space for variable-time multiscalar multiplication.
+///
+/// `VartimeMultiscalarMul::vartime_multiscalar_mul` allocates its
+/// NAF digits and lookup tables afresh on every call.  A
+/// `MultiscalarContext` keeps those buffers between calls, so a
+/// long-running verifier which performs many multiscalar
+/// multiplications only allocates until the buffers reach the largest
+/// input size.
+///
+/// The context always uses Straus' method, so for very large inputs
+/// the stateless function, which switches to Pippenger's method, may
+/// be faster despite its allocations.
+///
+/// A context is not thread-safe: `vartime_multiscalar_mul` takes
+/// `&mut self`, so each thread should own its own context rather than
+/// sharing one behind a lock.
+///
+/// # Example
 ///
-/// A precomputed table of multiples of the Ristretto basepoint is
-/// available in the `constants` module:
 /// ```
 /// use curve25519_dalek::constants;
+/// use curve25519_dalek::ristretto::MultiscalarContext;
 /// use curve25519_dalek::scalar::Scalar;
 ///
-/// let a = Scalar::from(87329482u64);
-/// let P = &a * &constants::RISTRETTO_BASEPOINT_TABLE;
+/// let B = constants::RISTRETTO_BASEPOINT_POINT;
+/// let mut context = MultiscalarContext::new();
+///
+/// for i in 0..4u64 {
+///     let P = context.vartime_multiscalar_mul(&[Scalar::from(i), Scalar::one()], &[B, B]);
+///     assert_eq!(P, B * Scalar::from(i + 1));
+/// }
 /// ```
-#[derive(Clone)]
-pub struct RistrettoBasepointTable(pub(crate) EdwardsBasepointTable);
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+pub struct MultiscalarContext(scalar_mul::straus::StrausWorkspace);
 
-impl<'a, 'b> Mul<&'b Scalar> for &'a RistrettoBasepointTable {
-    type Output = RistrettoPoint;
+#[cfg(feature = "alloc")]
+impl MultiscalarContext {
+    /// Create a context with empty scratch buffers.
+    pub fn new() -> MultiscalarContext {
+        MultiscalarContext::default()
+    }
 
-    fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
-        RistrettoPoint(&self.0 * scalar)
+    /// Given an iterator of public scalars and an iterator of public
+    /// points, compute \\( \sum\_i s\_i P\_i \\) in variable time,
+    /// reusing this context's scratch buffers.
+    ///
+    /// The result is the same as that of
+    /// `RistrettoPoint::vartime_multiscalar_mul`.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    pub fn vartime_multiscalar_mul<I, J>(&mut self, scalars: I, points: J) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        RistrettoPoint(scalar_mul::straus::Straus::vartime_multiscalar_mul_with_workspace(
+            &mut self.0,
+            scalars,
+            points.into_iter().map(|P| P.borrow().0),
+        ))
     }
 }
 
-impl<'a, 'b> Mul<&'a RistrettoBasepointTable> for &'b Scalar {
-    type Output = RistrettoPoint;
+/// An accumulator for a sum of `RistrettoPoint`s and scaled
+/// `RistrettoPoint`s which arrive incrementally.
+///
+/// Unscaled points are added immediately.  Scaled points are buffered,
+/// and computed with a single constant-time multiscalar multiplication
+/// in `finalize`, which is much cheaper than performing each scalar
+/// multiplication separately.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct RistrettoAccumulator {
+    sum: RistrettoPoint,
+    scalars: Vec<Scalar>,
+    points: Vec<RistrettoPoint>,
+}
 
-    fn mul(self, basepoint_table: &'a RistrettoBasepointTable) -> RistrettoPoint {
-        RistrettoPoint(self * &basepoint_table.0)
+#[cfg(feature = "alloc")]
+impl RistrettoAccumulator {
+    /// Create an accumulator whose value is the identity.
+    pub fn new() -> RistrettoAccumulator {
+        RistrettoAccumulator::default()
     }
-}
 
-impl RistrettoBasepointTable {
-    /// Create a precomputed table of multiples of the given `basepoint`.
-    pub fn create(basepoint: &RistrettoPoint) -> RistrettoBasepointTable {
-        RistrettoBasepointTable(EdwardsBasepointTable::create(&basepoint.0))
+    /// Add `point` to the accumulated sum.
+    pub fn add(&mut self, point: &RistrettoPoint) {
+        self.sum += point;
     }
 
-    /// Get the basepoint for this table as a `RistrettoPoint`.
-    pub fn basepoint(&self) -> RistrettoPoint {
-        RistrettoPoint(self.0.basepoint())
+    /// Add \\(sP\\) to the accumulated sum, where \\(s\\) is `scalar`
+    /// and \\(P\\) is `point`.
+    ///
+    /// The product is not computed until `finalize` is called.
+    pub fn add_scaled(&mut self, scalar: &Scalar, point: &RistrettoPoint) {
+        self.scalars.push(*scalar);
+        self.points.push(*point);
+    }
+
+    /// Consume the accumulator and return the accumulated sum.
+    pub fn finalize(self) -> RistrettoPoint {
+        if self.scalars.is_empty() {
+            return self.sum;
+        }
+        self.sum + RistrettoPoint::multiscalar_mul(&self.scalars, &self.points)
     }
 }
 
-// ------------------------------------------------------------------------
-// Constant-time conditional selection
-// ------------------------------------------------------------------------
+impl RistrettoPoint {
+    /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the
+    /// Ristretto basepoint.
+    pub fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &RistrettoPoint,
+        b: &Scalar,
+    ) -> RistrettoPoint {
+        RistrettoPoint(
+            EdwardsPoint::vartime_double_scalar_mul_basepoint(a, &A.0, b)
+        )
+    }
 
-impl ConditionallySelectable for RistrettoPoint {
-    /// Conditionally select between `self` and `other`.
+    /// Compute \\(sB - cA\\) in variable time, where \\(B\\) is the
+    /// Ristretto basepoint, for Schnorr-style verification.
+    ///
+    /// The caller checks a signature or proof by comparing the result
+    /// to the commitment \\(R\\).  This is
+    /// `vartime_double_scalar_mul_basepoint(&-c, A, s)`: the \\(sB\\)
+    /// term uses the precomputed table of odd multiples of \\(B\\), and
+    /// the \\(cA\\) term a width-5 NAF table of \\(A\\), sharing a
+    /// single chain of doublings.
+    ///
+    /// Since this is variable-time, all of the inputs must be public.
     ///
     /// # Example
     ///
     /// ```
-    /// # extern crate subtle;
-    /// # extern crate curve25519_dalek;
-    /// #
-    /// use subtle::ConditionallySelectable;
-    /// use subtle::Choice;
-    /// #
-    /// # use curve25519_dalek::traits::Identity;
-    /// # use curve25519_dalek::ristretto::RistrettoPoint;
-    /// # use curve25519_dalek::constants;
-    /// # fn main() {
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
     ///
-    /// let A = RistrettoPoint::identity();
     /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let (a, r, c) = (Scalar::from(11u64), Scalar::from(7u64), Scalar::from(3u64));
+    /// let A = B * a;
+    /// let R = B * r;
+    /// let s = r + c * a;
     ///
-    /// let mut P = A;
+    /// assert_eq!(RistrettoPoint::vartime_verify_dleq(&s, &c, &A), R);
+    /// ```
+    pub fn vartime_verify_dleq(s: &Scalar, c: &Scalar, A: &RistrettoPoint) -> RistrettoPoint {
+        RistrettoPoint::vartime_double_scalar_mul_basepoint(&-c, A, s)
+    }
+
+    /// Compute the products \\(s\_1 P\_1, \ldots, s\_n P\_n\\) of a
+    /// batch of independent (scalar, point) pairs, in variable time.
+    ///
+    /// The result is the same as computing `&s_i * &P_i` for each
+    /// pair, but the batch is processed in lockstep, which lets the
+    /// vectorized backend keep the whole computation in its parallel
+    /// point representation when `simd_backend` is enabled.
+    ///
+    /// Since this is variable-time, the scalars must be public.
     ///
-    /// P = RistrettoPoint::conditional_select(&A, &B, Choice::from(0));
-    /// assert_eq!(P, A);
-    /// P = RistrettoPoint::conditional_select(&A, &B, Choice::from(1));
-    /// assert_eq!(P, B);
-    /// # }
     /// ```
-    fn conditional_select(
-        a: &RistrettoPoint,
-        b: &RistrettoPoint,
-        choice: Choice,
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let pairs = [(Scalar::from(2u64), B), (Scalar::from(3u64), B + B)];
+    ///
+    /// let products = RistrettoPoint::vartime_batch_mul(&pairs);
+    ///
+    /// assert_eq!(products, vec![B + B, Scalar::from(6u64) * B]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn vartime_batch_mul(pairs: &[(Scalar, RistrettoPoint)]) -> Vec<RistrettoPoint> {
+        scalar_mul::vartime_batch::mul(pairs.iter().map(|(s, P)| (s, &P.0)))
+            .into_iter()
+            .map(RistrettoPoint)
+            .collect()
+    }
+
+    /// Check a batch of Schnorr-style verification equations
+    /// \\(s\_i B = R\_i + c\_i A\_i\\), in variable time, where
+    /// \\(B\\) is the Ristretto basepoint and each entry of `terms` is
+    /// \\((s\_i, R\_i, c\_i, A\_i)\\).
+    ///
+    /// # Implementation
+    ///
+    /// Random weights \\(z\_i\\) are drawn from `rng`, and the
+    /// single multiscalar multiplication
+    /// $$
+    /// \Big(\sum\_i z\_i s\_i\Big) B - \sum\_i z\_i R\_i - \sum\_i (z\_i c\_i) A\_i
+    /// $$
+    /// is compared to the identity.  If every equation holds, the sum
+    /// is the identity; if any equation fails, the sum is the identity
+    /// only with negligible probability over the choice of weights.
+    ///
+    /// Since this is variable-time, all of the inputs must be public.
+    ///
+    /// # Return
+    ///
+    /// - `true` if the combined equation holds, so that all of the
+    ///   individual equations hold with overwhelming probability;
+    /// - `false` otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn vartime_batch_verify<R>(
+        terms: &[(Scalar, RistrettoPoint, Scalar, RistrettoPoint)],
+        rng: &mut R,
+    ) -> bool
+    where
+        R: RngCore + CryptoRng,
+    {
+        let z: Vec<Scalar> = terms.iter().map(|_| Scalar::random(rng)).collect();
+
+        let B_coefficient: Scalar = terms.iter().zip(z.iter()).map(|((s, _, _, _), z)| z * s).sum();
+        let R_coefficients = z.iter().map(|z| -z);
+        let A_coefficients = terms.iter().zip(z.iter()).map(|((_, _, c, _), z)| -(z * c));
+
+        let points = iter::once(constants::RISTRETTO_BASEPOINT_POINT)
+            .chain(terms.iter().map(|(_, R, _, _)| *R))
+            .chain(terms.iter().map(|(_, _, _, A)| *A));
+
+        let check = RistrettoPoint::vartime_multiscalar_mul(
+            iter::once(B_coefficient).chain(R_coefficients).chain(A_coefficients),
+            points,
+        );
+
+        check == RistrettoPoint::identity()
+    }
+
+    /// Compute \\( \sum\_i a\_i B\_i + \sum\_j b\_j P\_j \\), in
+    /// variable time, where each \\( B\_i \\) is a fixed point with a
+    /// precomputed `RistrettoBasepointTable` and each \\( P\_j \\) is
+    /// an ad-hoc point.
+    ///
+    /// The fixed terms \\( (a\_i, B\_i) \\) are given by `fixed`, and
+    /// the dynamic terms by `dynamic_scalars` and `dynamic_points`.
+    ///
+    /// # Implementation
+    ///
+    /// Each fixed term is computed with its precomputed table, and the
+    /// dynamic terms with `vartime_multiscalar_mul`; the results are
+    /// then added.  Since this is variable-time, all of the inputs must
+    /// be public.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_TABLE.clone();
+    /// let P = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let a = Scalar::from(3u64);
+    /// let b = Scalar::from(4u64);
+    ///
+    /// let Q = RistrettoPoint::vartime_mixed_multiscalar_mul(&[(a, B)], &[b], &[P]);
+    /// assert_eq!(Q, &Scalar::from(7u64) * &P);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn vartime_mixed_multiscalar_mul<I, J>(
+        fixed: &[(Scalar, RistrettoBasepointTable)],
+        dynamic_scalars: I,
+        dynamic_points: J,
+    ) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        let fixed_sum: RistrettoPoint = fixed.iter().map(|(a, table)| table * a).sum();
+        let dynamic_sum = RistrettoPoint::vartime_multiscalar_mul(dynamic_scalars, dynamic_points);
+
+        fixed_sum + dynamic_sum
+    }
+
+    /// Compute \\(c\_1 P\_1 + \cdots + c\_n P\_n\\) in variable time,
+    /// using the given algorithm rather than choosing one by input size.
+    ///
+    /// The result is the same as `vartime_multiscalar_mul`; this is for
+    /// benchmarks and callers who want to pin the algorithm.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::edwards::MultiscalarAlgo;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let c = [Scalar::from(2u64), Scalar::from(3u64)];
+    ///
+    /// let Q = RistrettoPoint::vartime_multiscalar_mul_with_algo(MultiscalarAlgo::Pippenger, &c, &[B, B]);
+    /// assert_eq!(Q, &Scalar::from(5u64) * &B);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_with_algo<I, J>(
+        algo: MultiscalarAlgo,
+        scalars: I,
+        points: J,
+    ) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        let extended_points = points.into_iter().map(|P| P.borrow().0);
+        RistrettoPoint(EdwardsPoint::vartime_multiscalar_mul_with_algo(
+            algo,
+            scalars,
+            extended_points,
+        ))
+    }
+
+    /// Compute the linear combination
+    /// \\(c\_1 P\_1 + \cdots + c\_N P\_N\\) of a fixed number of
+    /// points, in constant time.
+    ///
+    /// This is the same constant-time Straus algorithm as
+    /// `RistrettoPoint::multiscalar_mul`, but the lookup tables and
+    /// scalar digits are kept in fixed-size arrays on the stack, so it
+    /// is available without `alloc` and avoids iterator overhead for
+    /// small `N`.
+    ///
+    /// This requires the `const-generics` feature.
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let c = [Scalar::from(2u64), Scalar::from(3u64)];
+    ///
+    /// assert_eq!(RistrettoPoint::linear_combination(&c, &[B, B]), Scalar::from(5u64) * B);
+    /// ```
+    #[cfg(feature = "const-generics")]
+    pub fn linear_combination<const N: usize>(
+        scalars: &[Scalar; N],
+        points: &[RistrettoPoint; N],
     ) -> RistrettoPoint {
-        RistrettoPoint(EdwardsPoint::conditional_select(&a.0, &b.0, choice))
+        use backend::serial::curve_models::ProjectiveNielsPoint;
+
+        let mut lookup_tables = [LookupTable::<ProjectiveNielsPoint>::default(); N];
+        for (table, P) in lookup_tables.iter_mut().zip(points.iter()) {
+            *table = LookupTable::from(&P.0);
+        }
+
+        let mut scalar_digits = [[0i8; 64]; N];
+        for (digits, s) in scalar_digits.iter_mut().zip(scalars.iter()) {
+            *digits = s.to_radix_16();
+        }
+
+        let mut Q = EdwardsPoint::identity();
+        for j in (0..64).rev() {
+            Q = Q.mul_by_pow_2(4);
+            for (digits, table) in scalar_digits.iter().zip(lookup_tables.iter()) {
+                Q = (&Q + &table.select(digits[j])).to_extended();
+            }
+        }
+
+        for digits in scalar_digits.iter_mut() {
+            digits.zeroize();
+        }
+
+        RistrettoPoint(Q)
+    }
+
+    /// Compute \\( \pm c\_1 P\_1 + \cdots + \pm c\_n P\_n \\) in
+    /// variable time, where each scalar carries its own sign.
+    ///
+    /// Each entry of `signed_scalars` is a pair `(is_negative, c_i)`,
+    /// and contributes \\(-c\_i P\_i\\) if `is_negative` is `true`, or
+    /// \\(c\_i P\_i\\) otherwise.  The sign is folded into the digits
+    /// of the scalar's non-adjacent form, so no points are negated.
+    ///
+    /// Since this is variable-time, the scalars and signs must be public.
+    ///
+    /// # Panics
+    ///
+    /// If `signed_scalars` and `points` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let terms = [(false, Scalar::from(5u64)), (true, Scalar::from(2u64))];
+    ///
+    /// let P = RistrettoPoint::vartime_multiscalar_mul_signed(&terms, &[B, B]);
+    ///
+    /// assert_eq!(P, Scalar::from(3u64) * B);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_signed<I, J>(signed_scalars: I, points: J) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<(bool, Scalar)>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        RistrettoPoint(scalar_mul::straus::Straus::vartime_multiscalar_mul_signed(
+            signed_scalars,
+            points.into_iter().map(|P| P.borrow().0),
+        ))
+    }
+
+    /// Compute the inner product
+    /// \\(\langle a, G \rangle = a\_1 G\_1 + \cdots + a\_n G\_n\\)
+    /// of a vector of scalars with a vector of points, in constant time.
+    ///
+    /// This is `RistrettoPoint::multiscalar_mul` on slices.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` and `points` have different lengths.
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let G = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let a = [Scalar::from(2u64), Scalar::from(3u64)];
+    ///
+    /// assert_eq!(RistrettoPoint::inner_product(&a, &[G, G]), Scalar::from(5u64) * G);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn inner_product(scalars: &[Scalar], points: &[RistrettoPoint]) -> RistrettoPoint {
+        assert_eq!(scalars.len(), points.len(), "inner product of vectors of different lengths");
+        RistrettoPoint::multiscalar_mul(scalars, points)
+    }
+
+    /// Compute the Pedersen commitment \\(vG + rH\\) in constant time.
+    ///
+    /// This runs a size-2 Straus multiscalar multiplication, so that
+    /// both terms share a single sequence of doublings, which is
+    /// faster than computing `v * G` and `r * H` separately.
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let G = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let H = RistrettoPoint::mul_base(&Scalar::from(5u64));
+    /// let v = Scalar::from(1000u64);
+    /// let r = Scalar::from(87329482u64);
+    ///
+    /// let C = RistrettoPoint::commit(&v, &G, &r, &H);
+    ///
+    /// assert_eq!(C, v * G + r * H);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn commit(
+        v: &Scalar,
+        G: &RistrettoPoint,
+        r: &Scalar,
+        H: &RistrettoPoint,
+    ) -> RistrettoPoint {
+        RistrettoPoint(
+            scalar_mul::straus::Straus::multiscalar_mul(&[*v, *r], &[G.0, H.0])
+        )
+    }
+
+    /// Fixed-base scalar multiplication by the Ristretto basepoint.
+    ///
+    /// This is a convenience wrapper around multiplication by
+    /// `constants::RISTRETTO_BASEPOINT_TABLE`, and runs in constant time.
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(87329482u64);
+    /// let P = RistrettoPoint::mul_base(&s);
+    ///
+    /// assert_eq!(P, &s * &constants::RISTRETTO_BASEPOINT_POINT);
+    /// ```
+    pub fn mul_base(scalar: &Scalar) -> RistrettoPoint {
+        &constants::RISTRETTO_BASEPOINT_TABLE * scalar
+    }
+
+    /// Compute \\(sP\\) in constant time, using a
+    /// `RistrettoLookupTable8` indexed by the signed radix-16 digits of
+    /// \\(s\\), each of which is clamped to \\(-8 \leq s\_i \leq 8\\).
+    ///
+    /// This computes the same result as `self * scalar`, with the
+    /// table lookups done on `RistrettoPoint`s rather than on
+    /// backend-specific point representations.
+    pub fn mul_clamped_ct(&self, scalar: &Scalar) -> RistrettoPoint {
+        let lookup_table = RistrettoLookupTable8::from(self);
+        let digits = scalar.to_radix_16();
+
+        // Horner's rule, from the most significant digit down:
+        // s*P = s_0*P + 16*(s_1*P + 16*( ... + 16*(s_63*P)...))
+        let mut Q = lookup_table.select(digits[63]);
+        for i in (0..63).rev() {
+            Q = RistrettoPoint(Q.0.mul_by_pow_2(4));
+            Q += lookup_table.select(digits[i]);
+        }
+        Q
+    }
+
+    /// Multiply this point by each of `scalars`, returning
+    /// \\( [s\_0 P, s\_1 P, \ldots] \\).
+    ///
+    /// The lookup table of multiples of `self` is built once and shared
+    /// across all of the multiplications, so this is faster than
+    /// computing `self * s` for each scalar separately.  Each
+    /// multiplication runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let P = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let scalars = [Scalar::from(2u64), Scalar::from(3u64)];
+    ///
+    /// let row = P.mul_each(&scalars);
+    /// assert_eq!(row, vec![P + P, P + P + P]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn mul_each(&self, scalars: &[Scalar]) -> Vec<RistrettoPoint> {
+        scalar_mul::variable_base::mul_each(&self.0, scalars)
+            .into_iter()
+            .map(RistrettoPoint)
+            .collect()
+    }
+}
+
+/// A precomputed table of multiples of a basepoint, used to accelerate
+/// scalar multiplication.
+///
+/// A precomputed table of multiples of the Ristretto basepoint is
+/// available in the `constants` module:
+/// ```
+/// use curve25519_dalek::constants;
+/// use curve25519_dalek::scalar::Scalar;
+///
+/// let a = Scalar::from(87329482u64);
+/// let P = &a * &constants::RISTRETTO_BASEPOINT_TABLE;
+/// ```
+#[derive(Clone)]
+pub struct RistrettoBasepointTable(pub(crate) BasepointTableWindow);
+
+/// The Edwards basepoint table backing a `RistrettoBasepointTable`.
+///
+/// Tables with windows larger than radix-16 are hundreds of kilobytes,
+/// so they are boxed.  The radix-16 table is kept inline so that
+/// `constants::RISTRETTO_BASEPOINT_TABLE` can be a `const`.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum BasepointTableWindow {
+    Radix16(EdwardsBasepointTable),
+    #[cfg(feature = "alloc")]
+    Radix32(Box<EdwardsBasepointTableRadix32>),
+    #[cfg(feature = "alloc")]
+    Radix64(Box<EdwardsBasepointTableRadix64>),
+    #[cfg(feature = "alloc")]
+    Radix128(Box<EdwardsBasepointTableRadix128>),
+    #[cfg(feature = "alloc")]
+    Radix256(Box<EdwardsBasepointTableRadix256>),
+}
+
+impl BasepointTableWindow {
+    fn basepoint_mul(&self, scalar: &Scalar) -> EdwardsPoint {
+        match self {
+            BasepointTableWindow::Radix16(table) => table * scalar,
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix32(table) => table.basepoint_mul(scalar),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix64(table) => table.basepoint_mul(scalar),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix128(table) => table.basepoint_mul(scalar),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix256(table) => table.basepoint_mul(scalar),
+        }
+    }
+
+    fn basepoint(&self) -> EdwardsPoint {
+        match self {
+            BasepointTableWindow::Radix16(table) => table.basepoint(),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix32(table) => table.basepoint(),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix64(table) => table.basepoint(),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix128(table) => table.basepoint(),
+            #[cfg(feature = "alloc")]
+            BasepointTableWindow::Radix256(table) => table.basepoint(),
+        }
+    }
+}
+
+impl<'a, 'b> Mul<&'b Scalar> for &'a RistrettoBasepointTable {
+    type Output = RistrettoPoint;
+
+    fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
+        RistrettoPoint(self.0.basepoint_mul(scalar))
+    }
+}
+
+impl<'a, 'b> Mul<&'a RistrettoBasepointTable> for &'b Scalar {
+    type Output = RistrettoPoint;
+
+    fn mul(self, basepoint_table: &'a RistrettoBasepointTable) -> RistrettoPoint {
+        RistrettoPoint(basepoint_table.0.basepoint_mul(self))
+    }
+}
+
+impl<'a> Mul<Scalar> for &'a RistrettoBasepointTable {
+    type Output = RistrettoPoint;
+
+    fn mul(self, scalar: Scalar) -> RistrettoPoint {
+        RistrettoPoint(self.0.basepoint_mul(&scalar))
+    }
+}
+
+impl<'a> Mul<&'a RistrettoBasepointTable> for Scalar {
+    type Output = RistrettoPoint;
+
+    fn mul(self, basepoint_table: &'a RistrettoBasepointTable) -> RistrettoPoint {
+        RistrettoPoint(basepoint_table.0.basepoint_mul(&self))
+    }
+}
+
+impl RistrettoBasepointTable {
+    /// Create a precomputed table of multiples of the given `basepoint`.
+    pub fn create(basepoint: &RistrettoPoint) -> RistrettoBasepointTable {
+        RistrettoBasepointTable(BasepointTableWindow::Radix16(
+            EdwardsBasepointTable::create(&basepoint.0),
+        ))
+    }
+
+    /// Create a precomputed table of multiples of the given
+    /// `basepoint`, using a radix-\\(2\^w\\) window, where \\(w\\) is
+    /// `window`.
+    ///
+    /// Larger windows trade memory for speed.  The table sizes and
+    /// the number of additions per scalar multiplication are:
+    ///
+    /// * `window = 4`: 30KB, 64A (the same table as `create`)
+    /// * `window = 5`: 60KB, 52A
+    /// * `window = 6`: 120KB, 43A
+    /// * `window = 7`: 240KB, 37A
+    /// * `window = 8`: 480KB, 33A
+    ///
+    /// The result of a multiplication does not depend on the window.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is not in the range \\(4 \leq w \leq 8\\).
+    #[cfg(feature = "alloc")]
+    pub fn create_with_window(basepoint: &RistrettoPoint, window: usize) -> RistrettoBasepointTable {
+        let B = &basepoint.0;
+        let table = match window {
+            4 => BasepointTableWindow::Radix16(EdwardsBasepointTable::create(B)),
+            5 => BasepointTableWindow::Radix32(Box::new(EdwardsBasepointTableRadix32::create(B))),
+            6 => BasepointTableWindow::Radix64(Box::new(EdwardsBasepointTableRadix64::create(B))),
+            7 => BasepointTableWindow::Radix128(Box::new(EdwardsBasepointTableRadix128::create(B))),
+            8 => BasepointTableWindow::Radix256(Box::new(EdwardsBasepointTableRadix256::create(B))),
+            _ => panic!("window must be between 4 and 8, got {}", window),
+        };
+        RistrettoBasepointTable(table)
+    }
+
+    /// Get the basepoint for this table as a `RistrettoPoint`.
+    pub fn basepoint(&self) -> RistrettoPoint {
+        RistrettoPoint(self.0.basepoint())
+    }
+
+    /// Get the basepoint for this table as a `CompressedRistretto`.
+    ///
+    /// This is equivalent to `table.basepoint().compress()`.
+    pub fn basepoint_compressed(&self) -> CompressedRistretto {
+        self.basepoint().compress()
+    }
+}
+
+/// Precomputed `RistrettoBasepointTable`s for a fixed list of
+/// generators \\(G\_1, \ldots, G\_n\\), for computing many commitments
+/// \\(c\_1 G\_1 + \cdots + c\_n G\_n\\) to the same generators.
+///
+/// Building the tables is expensive, but each `commit` then uses only
+/// fixed-base multiplications, which is cheaper than a multiscalar
+/// multiplication over the same points once enough commitments are made.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants;
+/// use curve25519_dalek::ristretto::RistrettoGeneratorTables;
+/// use curve25519_dalek::scalar::Scalar;
+///
+/// let B = constants::RISTRETTO_BASEPOINT_POINT;
+/// let tables = RistrettoGeneratorTables::new(&[B, B + B]);
+///
+/// let C = tables.commit(&[Scalar::from(3u64), Scalar::from(2u64)]);
+/// assert_eq!(C, &Scalar::from(7u64) * &B);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct RistrettoGeneratorTables(Vec<RistrettoBasepointTable>);
+
+#[cfg(feature = "alloc")]
+impl RistrettoGeneratorTables {
+    /// Create a table for each of the given `generators`.
+    pub fn new(generators: &[RistrettoPoint]) -> RistrettoGeneratorTables {
+        RistrettoGeneratorTables(generators.iter().map(RistrettoBasepointTable::create).collect())
+    }
+
+    /// Compute \\(c\_1 G\_1 + \cdots + c\_n G\_n\\) in constant time,
+    /// where the \\(c\_i\\) are `scalars`.
+    ///
+    /// # Panics
+    ///
+    /// If `scalars` does not have one entry per generator.
+    pub fn commit(&self, scalars: &[Scalar]) -> RistrettoPoint {
+        assert_eq!(scalars.len(), self.0.len());
+        self.0.iter().zip(scalars.iter()).map(|(table, c)| table * c).sum()
+    }
+}
+
+/// A table of the multiples \\(P, 2P, \ldots, 8P\\) of a
+/// `RistrettoPoint`, for constant-time lookups of \\(xP\\) with
+/// \\(-8 \leq x \leq 8\\).
+///
+/// This is the building block for a constant-time variable-base
+/// scalar multiplication indexed by secret signed radix-16 digits;
+/// see `RistrettoPoint::mul_clamped_ct`.
+#[derive(Copy, Clone, Debug)]
+pub struct RistrettoLookupTable8(pub(crate) LookupTable<RistrettoPoint>);
+
+impl RistrettoLookupTable8 {
+    /// Given \\(-8 \leq x \leq 8\\), return \\(xP\\) in constant time.
+    ///
+    /// Every entry of the table is read, and a negative `x` is
+    /// handled by a conditional negation, so neither the magnitude
+    /// nor the sign of `x` is revealed.
+    pub fn select(&self, x: i8) -> RistrettoPoint {
+        self.0.select(x)
+    }
+}
+
+impl<'a> From<&'a RistrettoPoint> for RistrettoLookupTable8 {
+    fn from(P: &'a RistrettoPoint) -> Self {
+        let mut points = [*P; 8];
+        for j in 0..7 {
+            points[j + 1] = P + points[j];
+        }
+        RistrettoLookupTable8(LookupTable(points))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Cached compressed points
+// ------------------------------------------------------------------------
+
+/// A `RistrettoPoint` together with its `CompressedRistretto` encoding,
+/// computed once when the `CachedRistrettoPoint` is created.
+///
+/// This is for long-lived points, such as public keys, which are
+/// repeatedly compared or serialized: `compressed()` and equality use
+/// the stored encoding instead of recompressing.  The point itself is
+/// available through `Deref`.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::constants;
+/// use curve25519_dalek::ristretto::CachedRistrettoPoint;
+///
+/// let B = CachedRistrettoPoint::from(constants::RISTRETTO_BASEPOINT_POINT);
+///
+/// assert_eq!(B.compressed(), &constants::RISTRETTO_BASEPOINT_COMPRESSED);
+/// assert_eq!(*B, constants::RISTRETTO_BASEPOINT_POINT);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CachedRistrettoPoint {
+    point: RistrettoPoint,
+    compressed: CompressedRistretto,
+}
+
+impl CachedRistrettoPoint {
+    /// Get the cached encoding of the point.
+    pub fn compressed(&self) -> &CompressedRistretto {
+        &self.compressed
+    }
+
+    /// Get the point.
+    pub fn point(&self) -> &RistrettoPoint {
+        &self.point
+    }
+}
+
+impl From<RistrettoPoint> for CachedRistrettoPoint {
+    fn from(point: RistrettoPoint) -> CachedRistrettoPoint {
+        CachedRistrettoPoint {
+            point,
+            compressed: point.compress(),
+        }
+    }
+}
+
+impl Deref for CachedRistrettoPoint {
+    type Target = RistrettoPoint;
+
+    fn deref(&self) -> &RistrettoPoint {
+        &self.point
+    }
+}
+
+impl ConstantTimeEq for CachedRistrettoPoint {
+    /// Compare the cached encodings in constant time.
+    fn ct_eq(&self, other: &CachedRistrettoPoint) -> Choice {
+        self.compressed.ct_eq(&other.compressed)
+    }
+}
+
+impl PartialEq for CachedRistrettoPoint {
+    fn eq(&self, other: &CachedRistrettoPoint) -> bool {
+        self.ct_eq(other).unwrap_u8() == 1u8
+    }
+}
+
+impl Eq for CachedRistrettoPoint {}
+
+// ------------------------------------------------------------------------
+// Ciphersuites
+// ------------------------------------------------------------------------
+
+/// A choice of hash function and domain separation tag for a protocol
+/// built on ristretto255.
+///
+/// Implementors fix `Hash` and `DOMAIN`; the provided methods derive
+/// scalars and group elements from them, so protocol code can be
+/// written once, generic over `S: RistrettoCiphersuite`.
+///
+/// Every hash input is prefixed with the length of `DOMAIN`, `DOMAIN`
+/// itself, and a byte distinguishing `hash_to_scalar` from
+/// `hash_to_group`, so the two never hash the same string.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "sha2")]
+/// # {
+/// use curve25519_dalek::ristretto::{RistrettoCiphersuite, Sha512Ristretto};
+///
+/// let s = Sha512Ristretto::hash_to_scalar(b"input");
+/// let P = Sha512Ristretto::hash_to_group(b"input");
+/// assert_eq!(Sha512Ristretto::generator() * s, s * Sha512Ristretto::generator());
+/// # let _ = P;
+/// # }
+/// ```
+pub trait RistrettoCiphersuite {
+    /// The hash function used to derive scalars and group elements.
+    type Hash: Digest<OutputSize = U64> + Default;
+
+    /// The domain separation tag for this suite.
+    const DOMAIN: &'static [u8];
+
+    /// Hash `msg` to a `Scalar`.
+    fn hash_to_scalar(msg: &[u8]) -> Scalar {
+        Scalar::from_hash(ciphersuite_hash::<Self>(0, msg))
+    }
+
+    /// Hash `msg` to a `RistrettoPoint`.
+    fn hash_to_group(msg: &[u8]) -> RistrettoPoint {
+        RistrettoPoint::from_hash(ciphersuite_hash::<Self>(1, msg))
+    }
+
+    /// The generator used by this suite; the Ristretto basepoint
+    /// unless overridden.
+    fn generator() -> RistrettoPoint {
+        constants::RISTRETTO_BASEPOINT_POINT
+    }
+}
+
+/// Start a `S::Hash` instance over `len(DOMAIN) || DOMAIN || tag || msg`,
+/// with the length as 8 little-endian bytes.
+fn ciphersuite_hash<S: RistrettoCiphersuite + ?Sized>(tag: u8, msg: &[u8]) -> S::Hash {
+    S::Hash::default()
+        .chain((S::DOMAIN.len() as u64).to_le_bytes())
+        .chain(S::DOMAIN)
+        .chain([tag])
+        .chain(msg)
+}
+
+/// The default `RistrettoCiphersuite`, using SHA-512.
+#[cfg(feature = "sha2")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Sha512Ristretto;
+
+#[cfg(feature = "sha2")]
+impl RistrettoCiphersuite for Sha512Ristretto {
+    type Hash = ::sha2::Sha512;
+
+    const DOMAIN: &'static [u8] = b"curve25519-dalek ristretto255 SHA-512";
+}
+
+// ------------------------------------------------------------------------
+// Constant-time conditional selection
+// ------------------------------------------------------------------------
+
+impl ConditionallySelectable for RistrettoPoint {
+    /// Conditionally select between `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate subtle;
+    /// # extern crate curve25519_dalek;
+    /// #
+    /// use subtle::ConditionallySelectable;
+    /// use subtle::Choice;
+    /// #
+    /// # use curve25519_dalek::traits::Identity;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// # use curve25519_dalek::constants;
+    /// # fn main() {
+    ///
+    /// let A = RistrettoPoint::identity();
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    ///
+    /// let mut P = A;
+    ///
+    /// P = RistrettoPoint::conditional_select(&A, &B, Choice::from(0));
+    /// assert_eq!(P, A);
+    /// P = RistrettoPoint::conditional_select(&A, &B, Choice::from(1));
+    /// assert_eq!(P, B);
+    /// # }
+    /// ```
+    fn conditional_select(
+        a: &RistrettoPoint,
+        b: &RistrettoPoint,
+        choice: Choice,
+    ) -> RistrettoPoint {
+        RistrettoPoint(EdwardsPoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+// `ConditionallyNegatable` is provided for `RistrettoPoint` by the
+// generic implementation in `subtle`, which combines the constant-time
+// `ConditionallySelectable` impl above with `Neg for &RistrettoPoint`.
+
+impl RistrettoPoint {
+    /// Add `term` to `self` if `choice` is set, in constant time.
+    ///
+    /// The sum is always computed, and then assigned with
+    /// `conditional_assign`, so neither timing nor memory access
+    /// patterns depend on `choice`.  This replaces the manual
+    /// `conditional_select(&acc, &(&acc + term), choice)` pattern.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate subtle;
+    /// # extern crate curve25519_dalek;
+    /// # use subtle::Choice;
+    /// # use curve25519_dalek::traits::Identity;
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// # use curve25519_dalek::constants;
+    /// # fn main() {
+    /// let B = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let mut acc = RistrettoPoint::identity();
+    ///
+    /// acc.conditional_add(&B, Choice::from(0));
+    /// assert_eq!(acc, RistrettoPoint::identity());
+    /// acc.conditional_add(&B, Choice::from(1));
+    /// assert_eq!(acc, B);
+    /// # }
+    /// ```
+    pub fn conditional_add(&mut self, term: &RistrettoPoint, choice: Choice) {
+        let sum = *self + term;
+        self.conditional_assign(&sum, choice);
+    }
+}
+
+// ------------------------------------------------------------------------
+// Debug traits
+// ------------------------------------------------------------------------
+
+impl Debug for CompressedRistretto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "CompressedRistretto: {:?}", self.as_bytes())
+    }
+}
+
+/// Formats the encoding as 64 digits of lowercase hex, in wire order,
+/// matching `CompressedRistretto::to_hex`.
+impl Display for CompressedRistretto {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for RistrettoPoint {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        let coset = self.coset4();
+        write!(f, "RistrettoPoint: coset \n{:?}\n{:?}\n{:?}\n{:?}",
+               coset[0], coset[1], coset[2], coset[3])
+    }
+}
+
+// ------------------------------------------------------------------------
+// Zeroize traits
+// ------------------------------------------------------------------------
+
+impl Zeroize for CompressedRistretto {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Zeroize for RistrettoPoint {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use rand_core::OsRng;
+
+    use scalar::Scalar;
+    use constants;
+    use traits::{Identity};
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_basepoint_roundtrip() {
+        use bincode;
+
+        let encoded = bincode::serialize(&constants::RISTRETTO_BASEPOINT_POINT).unwrap();
+        let enc_compressed = bincode::serialize(&constants::RISTRETTO_BASEPOINT_COMPRESSED).unwrap();
+        assert_eq!(encoded, enc_compressed);
+
+        // Check that the encoding is 32 bytes exactly
+        assert_eq!(encoded.len(), 32);
+
+        let dec_uncompressed: RistrettoPoint = bincode::deserialize(&encoded).unwrap();
+        let dec_compressed: CompressedRistretto = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(dec_uncompressed, constants::RISTRETTO_BASEPOINT_POINT);
+        assert_eq!(dec_compressed, constants::RISTRETTO_BASEPOINT_COMPRESSED);
+
+        // Check that the encoding itself matches the usual one
+        let raw_bytes = constants::RISTRETTO_BASEPOINT_COMPRESSED.as_bytes();
+        let bp: RistrettoPoint = bincode::deserialize(raw_bytes).unwrap();
+        assert_eq!(bp, constants::RISTRETTO_BASEPOINT_POINT);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_struct_field_has_no_length_prefix() {
+        use bincode::Options;
+
+        #[derive(Serialize, Deserialize)]
+        struct PublicKeys {
+            A: RistrettoPoint,
+            B: CompressedRistretto,
+        }
+
+        let keys = PublicKeys {
+            A: constants::RISTRETTO_BASEPOINT_POINT,
+            B: constants::RISTRETTO_BASEPOINT_COMPRESSED,
+        };
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        let encoded = options.serialize(&keys).unwrap();
+        assert_eq!(encoded.len(), 64);
+
+        let decoded: PublicKeys = options.deserialize(&encoded).unwrap();
+        assert_eq!(decoded.A, keys.A);
+        assert_eq!(decoded.B, keys.B);
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "const-generics"))]
+    fn serde_cbor_compressed_ristretto_array() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        let array = CompressedRistrettoArray([
+            B.compress(),
+            (B + B).compress(),
+            CompressedRistretto::identity(),
+        ]);
+
+        let encoded = serde_cbor::to_vec(&array).unwrap();
+        // A CBOR byte string header of two bytes, then the 96 bytes.
+        assert_eq!(encoded[..2], [0x58, 96]);
+        assert_eq!(encoded.len(), 2 + 96);
+        let decoded: CompressedRistrettoArray<3> = serde_cbor::from_slice(&encoded).unwrap();
+        assert_eq!(decoded, array);
+
+        let too_short: Result<CompressedRistrettoArray<4>, _> = serde_cbor::from_slice(&encoded);
+        assert!(too_short.is_err());
+
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_distinguishes_errors() {
+        use bincode;
+
+        let short = [0u8; 31];
+        let short_err = bincode::deserialize::<RistrettoPoint>(&short).unwrap_err();
+
+        // Has the right length, but the high bit set in the last byte.
+        let mut invalid = [0u8; 32];
+        invalid[31] = 0x80;
+        let invalid_err = bincode::deserialize::<RistrettoPoint>(&invalid).unwrap_err();
+
+        assert_ne!(format!("{}", short_err), format!("{}", invalid_err));
+        assert!(format!("{}", invalid_err).contains("Ristretto point"));
+
+        assert!(bincode::deserialize::<CompressedRistretto>(&short).is_err());
+        assert!(bincode::deserialize::<CompressedRistretto>(&invalid).is_ok());
+    }
+
+    #[test]
+    fn scalarmult_ristrettopoint_works_both_ways() {
+        let P = constants::RISTRETTO_BASEPOINT_POINT;
+        let s = Scalar::from(999u64);
+
+        let P1 = &P * &s;
+        let P2 = &s * &P;
+
+        assert!(P1.compress().as_bytes() == P2.compress().as_bytes());
+    }
+
+    #[test]
+    fn impl_sum() {
+
+        // Test that sum works for non-empty iterators
+        let BASE = constants::RISTRETTO_BASEPOINT_POINT;
+
+        let s1 = Scalar::from(999u64);
+        let P1 = &BASE * &s1;
+
+        let s2 = Scalar::from(333u64);
+        let P2 = &BASE * &s2;
+
+        let vec = vec![P1.clone(), P2.clone()];
+        let sum: RistrettoPoint = vec.iter().sum();
+
+        assert_eq!(sum, P1 + P2);
+
+        // Test that sum works for the empty iterator
+        let empty_vector: Vec<RistrettoPoint> = vec![];
+        let sum: RistrettoPoint = empty_vector.iter().sum();
+
+        assert_eq!(sum, RistrettoPoint::identity());
+
+        // Test that sum works on owning iterators
+        let s = Scalar::from(2u64);
+        let mapped = vec.iter().map(|x| x * s);
+        let sum: RistrettoPoint = mapped.sum();
+
+        assert_eq!(sum, &P1 * &s + &P2 * &s);
+    }
+
+    #[test]
+    fn vartime_multiscalar_mul_signed() {
+        let mut rng = OsRng;
+        let terms: Vec<(bool, Scalar)> = (0..8)
+            .map(|i| (i % 3 == 0, Scalar::random(&mut rng)))
+            .collect();
+        let points: Vec<RistrettoPoint> =
+            (0..8).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+        let negated_points = terms.iter().zip(points.iter())
+            .map(|((is_negative, _), P)| if *is_negative { -P } else { *P });
+        let expected = RistrettoPoint::vartime_multiscalar_mul(
+            terms.iter().map(|(_, c)| c),
+            negated_points,
+        );
+
+        assert_eq!(RistrettoPoint::vartime_multiscalar_mul_signed(&terms, &points), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vartime_multiscalar_mul_signed_length_mismatch_panics() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        let terms = [(false, Scalar::one()), (true, Scalar::one())];
+        RistrettoPoint::vartime_multiscalar_mul_signed(&terms, &[B]);
+    }
+
+    #[cfg(feature = "const-generics")]
+    #[test]
+    fn linear_combination() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let Q = RistrettoPoint::random(&mut rng);
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+
+        assert_eq!(RistrettoPoint::linear_combination(&[a, b], &[P, Q]), &(a * P) + &(b * Q));
+        assert_eq!(RistrettoPoint::linear_combination::<0>(&[], &[]), RistrettoPoint::identity());
+    }
+
+    #[test]
+    fn inner_product_matches_fold() {
+        let mut rng = OsRng;
+        let a: Vec<Scalar> = (0..16).map(|_| Scalar::random(&mut rng)).collect();
+        let G: Vec<RistrettoPoint> = (0..16).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+        let expected = a.iter().zip(G.iter())
+            .fold(RistrettoPoint::identity(), |acc, (a_i, G_i)| acc + a_i * G_i);
+
+        assert_eq!(RistrettoPoint::inner_product(&a, &G), expected);
+        assert_eq!(RistrettoPoint::inner_product(&[], &[]), RistrettoPoint::identity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn inner_product_length_mismatch_panics() {
+        let G = constants::RISTRETTO_BASEPOINT_POINT;
+        RistrettoPoint::inner_product(&[Scalar::one()], &[G, G]);
+    }
+
+    #[test]
+    fn multiscalar_mul_with_owned_inputs() {
+        let mut rng = OsRng;
+        let points: Vec<RistrettoPoint> =
+            (0..4).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        let scalars: Vec<Scalar> = (1..5u64).map(Scalar::from).collect();
+
+        let expected = RistrettoPoint::multiscalar_mul(&scalars, &points);
+
+        // Owned scalars, borrowed points
+        let owned = (1..5u64).map(Scalar::from);
+        assert_eq!(RistrettoPoint::multiscalar_mul(owned, points.iter()), expected);
+
+        // Owned scalars, owned points
+        let owned = (1..5u64).map(Scalar::from);
+        assert_eq!(RistrettoPoint::vartime_multiscalar_mul(owned, points.clone()), expected);
+    }
+
+    #[test]
+    fn accumulator_matches_multiscalar_mul() {
+        let mut rng = OsRng;
+        let scalars: Vec<Scalar> = (0..8).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<RistrettoPoint> =
+            (0..8).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        let extra = RistrettoPoint::random(&mut rng);
+
+        let mut acc = RistrettoAccumulator::new();
+        for (s, P) in scalars.iter().zip(points.iter()) {
+            acc.add_scaled(s, P);
+        }
+        acc.add(&extra);
+
+        let expected = RistrettoPoint::multiscalar_mul(&scalars, &points) + extra;
+        assert_eq!(acc.finalize(), expected);
+
+        assert_eq!(RistrettoAccumulator::new().finalize(), RistrettoPoint::identity());
+    }
+
+    #[test]
+    fn basepoint_table_with_window() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+
+        for &window in &[5, 6] {
+            let table = RistrettoBasepointTable::create_with_window(&P, window);
+            assert_eq!(table.basepoint(), P);
+            for _ in 0..4 {
+                let s = Scalar::random(&mut rng);
+                assert_eq!(&table * &s, &P * &s);
+                assert_eq!(&s * &table, &P * &s);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn basepoint_table_with_invalid_window_panics() {
+        RistrettoBasepointTable::create_with_window(&constants::RISTRETTO_BASEPOINT_POINT, 9);
+    }
+
+    #[test]
+    fn vartime_batch_verify() {
+        let mut rng = OsRng;
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+
+        let mut terms: Vec<(Scalar, RistrettoPoint, Scalar, RistrettoPoint)> = (0..8)
+            .map(|_| {
+                let a = Scalar::random(&mut rng);
+                let r = Scalar::random(&mut rng);
+                let c = Scalar::random(&mut rng);
+                (r + c * a, r * B, c, a * B)
+            })
+            .collect();
+
+        assert!(RistrettoPoint::vartime_batch_verify(&terms, &mut rng));
+        assert!(RistrettoPoint::vartime_batch_verify(&[], &mut rng));
+
+        terms[3].0 += Scalar::one();
+        assert!(!RistrettoPoint::vartime_batch_verify(&terms, &mut rng));
+    }
+
+    #[test]
+    fn ct_eq_batch() {
+        let mut rng = OsRng;
+        let a: Vec<RistrettoPoint> = (0..8).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        let mut b = a.clone();
+        assert_eq!(RistrettoPoint::ct_eq_batch(&a, &b), 1u8);
+        assert_eq!(RistrettoPoint::ct_eq_batch(&[], &[]), 1u8);
+
+        b[5] = -b[5];
+        assert_eq!(RistrettoPoint::ct_eq_batch(&a, &b), 0u8);
+
+        assert_eq!(RistrettoPoint::ct_eq_batch(&a[..7], &a), 0u8);
+    }
+
+    #[test]
+    fn is_valid() {
+        let P = constants::RISTRETTO_BASEPOINT_COMPRESSED.decompress().unwrap();
+        assert!(P.is_valid());
+        assert!(RistrettoPoint::identity().is_valid());
+
+        // Negating T alone breaks the relation XY = ZT between the
+        // extended coordinates.
+        let mut Q = P;
+        Q.0.T = -&Q.0.T;
+        assert!(!Q.is_valid());
+    }
+
+    #[test]
+    fn lookup_table_select() {
+        let P = constants::RISTRETTO_BASEPOINT_POINT;
+        let table = RistrettoLookupTable8::from(&P);
+
+        for x in -8i8..=8 {
+            let expected = if x < 0 {
+                -(&P * &Scalar::from((-x) as u64))
+            } else {
+                &P * &Scalar::from(x as u64)
+            };
+            assert_eq!(table.select(x), expected);
+        }
+    }
+
+    #[test]
+    fn vartime_mixed_multiscalar_mul_matches_sum() {
+        let mut rng = OsRng;
+        let G = RistrettoPoint::random(&mut rng);
+        let H = RistrettoPoint::random(&mut rng);
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let fixed = [
+            (a, RistrettoBasepointTable::create(&G)),
+            (b, RistrettoBasepointTable::create(&H)),
+        ];
+
+        let scalars: Vec<Scalar> = (0..8).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<RistrettoPoint> = (0..8).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+        let mut expected = &a * &G + &b * &H;
+        for (s, P) in scalars.iter().zip(points.iter()) {
+            expected += s * P;
+        }
+
+        let result = RistrettoPoint::vartime_mixed_multiscalar_mul(&fixed, &scalars, &points);
+        assert_eq!(result, expected);
+
+        let no_dynamic: [Scalar; 0] = [];
+        let no_points: [RistrettoPoint; 0] = [];
+        assert_eq!(
+            RistrettoPoint::vartime_mixed_multiscalar_mul(&fixed, &no_dynamic, &no_points),
+            &a * &G + &b * &H
+        );
+    }
+
+    #[test]
+    fn generator_tables_commit_matches_multiscalar_mul() {
+        let mut rng = OsRng;
+        let generators: Vec<RistrettoPoint> = (0..8).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        let tables = RistrettoGeneratorTables::new(&generators);
+
+        for _ in 0..4 {
+            let scalars: Vec<Scalar> = (0..8).map(|_| Scalar::random(&mut rng)).collect();
+            assert_eq!(
+                tables.commit(&scalars),
+                RistrettoPoint::multiscalar_mul(&scalars, &generators)
+            );
+        }
+
+        assert_eq!(RistrettoGeneratorTables::new(&[]).commit(&[]), RistrettoPoint::identity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn generator_tables_commit_length_mismatch_panics() {
+        let tables = RistrettoGeneratorTables::new(&[constants::RISTRETTO_BASEPOINT_POINT]);
+        tables.commit(&[]);
+    }
+
+    #[test]
+    fn vartime_multiscalar_mul_with_algo_agree() {
+        let mut rng = OsRng;
+        let scalars: Vec<Scalar> = (0..32).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<RistrettoPoint> = (0..32).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+        let straus = RistrettoPoint::vartime_multiscalar_mul_with_algo(
+            MultiscalarAlgo::Straus,
+            &scalars,
+            &points,
+        );
+        let pippenger = RistrettoPoint::vartime_multiscalar_mul_with_algo(
+            MultiscalarAlgo::Pippenger,
+            &scalars,
+            &points,
+        );
+
+        assert_eq!(straus, pippenger);
+        assert_eq!(straus, RistrettoPoint::vartime_multiscalar_mul(&scalars, &points));
+    }
+
+    #[test]
+    fn multiscalar_mul_empty_and_single() {
+        let no_scalars: [Scalar; 0] = [];
+        let no_points: [RistrettoPoint; 0] = [];
+        assert_eq!(
+            RistrettoPoint::multiscalar_mul(&no_scalars, &no_points),
+            RistrettoPoint::identity()
+        );
+
+        let s = Scalar::from(7u64);
+        let P = constants::RISTRETTO_BASEPOINT_POINT;
+        assert_eq!(RistrettoPoint::multiscalar_mul(&[s], &[P]), &s * &P);
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiscalar_mul_mismatched_lengths() {
+        let P = constants::RISTRETTO_BASEPOINT_POINT;
+        RistrettoPoint::multiscalar_mul(&[Scalar::one(), Scalar::one()], &[P]);
+    }
+
+    #[test]
+    fn mul_each_matches_mul() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let scalars: Vec<Scalar> = (0..16).map(|_| Scalar::random(&mut rng)).collect();
+
+        let row = P.mul_each(&scalars);
+        assert_eq!(row.len(), scalars.len());
+        for i in 0..scalars.len() {
+            assert_eq!(row[i], &P * &scalars[i]);
+        }
+        assert!(P.mul_each(&[]).is_empty());
+    }
+
+    #[test]
+    fn mul_clamped_ct_matches_mul() {
+        let mut rng = OsRng;
+        for _ in 0..16 {
+            let P = RistrettoPoint::random(&mut rng);
+            let s = Scalar::random(&mut rng);
+            assert_eq!(P.mul_clamped_ct(&s), &P * &s);
+        }
+
+        let P = constants::RISTRETTO_BASEPOINT_POINT;
+        assert_eq!(P.mul_clamped_ct(&Scalar::zero()), RistrettoPoint::identity());
+        assert_eq!(P.mul_clamped_ct(&-Scalar::one()), -P);
+    }
+
+    #[test]
+    fn encode_decode_batch() {
+        let empty = RistrettoPoint::encode_batch(&[]);
+        assert_eq!(empty, vec![0u8; 4]);
+        assert_eq!(RistrettoPoint::decode_batch(&empty), Some(vec![]));
+
+        let mut rng = OsRng;
+        let points: Vec<RistrettoPoint> =
+            (0..3).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        let bytes = RistrettoPoint::encode_batch(&points);
+        assert_eq!(bytes.len(), 4 + 3 * 32);
+        assert_eq!(&bytes[..4], &[3, 0, 0, 0]);
+        assert_eq!(RistrettoPoint::decode_batch(&bytes), Some(points));
+
+        // Truncated input, a bad count, and an invalid encoding are rejected.
+        assert_eq!(RistrettoPoint::decode_batch(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(RistrettoPoint::decode_batch(&bytes[..2]), None);
+        let mut bad_count = bytes.clone();
+        bad_count[0] = 2;
+        assert_eq!(RistrettoPoint::decode_batch(&bad_count), None);
+        let mut bad_point = bytes;
+        bad_point[4] = 1;
+        assert_eq!(RistrettoPoint::decode_batch(&bad_point), None);
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn from_label_is_deterministic() {
+        use sha2::Sha512;
+
+        let H_1 = RistrettoPoint::from_label(b"H");
+        let H_2 = RistrettoPoint::from_label(b"H");
+        let G = RistrettoPoint::from_label(b"G");
+
+        assert_eq!(H_1.compress(), H_2.compress());
+        assert_ne!(H_1.compress(), G.compress());
+        // The domain separator keeps this distinct from hash_from_bytes.
+        assert_ne!(H_1, RistrettoPoint::hash_from_bytes::<Sha512>(b"H"));
     }
-}
 
-// ------------------------------------------------------------------------
-// Debug traits
-// ------------------------------------------------------------------------
+    #[test]
+    fn display_compressed_as_hex() {
+        assert_eq!(
+            format!("{}", constants::RISTRETTO_BASEPOINT_COMPRESSED),
+            "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76"
+        );
+        assert_eq!(
+            format!("{}", CompressedRistretto::identity()),
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
 
-impl Debug for CompressedRistretto {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "CompressedRistretto: {:?}", self.as_bytes())
+    #[test]
+    fn basepoint_table_basepoint_compressed() {
+        let table = &constants::RISTRETTO_BASEPOINT_TABLE;
+        assert_eq!(table.basepoint_compressed(), table.basepoint().compress());
+        assert_eq!(
+            table.basepoint_compressed(),
+            constants::RISTRETTO_BASEPOINT_COMPRESSED
+        );
     }
-}
 
-impl Debug for RistrettoPoint {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        let coset = self.coset4();
-        write!(f, "RistrettoPoint: coset \n{:?}\n{:?}\n{:?}\n{:?}",
-               coset[0], coset[1], coset[2], coset[3])
+    #[test]
+    fn basepoint_table_mul_owned_scalar() {
+        let s = Scalar::from(87329482u64);
+        let expected = &s * &constants::RISTRETTO_BASEPOINT_TABLE;
+
+        let P = s * &constants::RISTRETTO_BASEPOINT_TABLE;
+        let Q = &constants::RISTRETTO_BASEPOINT_TABLE * s;
+        assert_eq!(P, expected);
+        assert_eq!(Q, expected);
     }
-}
 
-// ------------------------------------------------------------------------
-// Zeroize traits
-// ------------------------------------------------------------------------
+    #[test]
+    fn conditional_select_identity_and_basepoint() {
+        let A = RistrettoPoint::identity();
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
 
-impl Zeroize for CompressedRistretto {
-    fn zeroize(&mut self) {
-        self.0.zeroize();
+        assert_eq!(RistrettoPoint::conditional_select(&A, &B, Choice::from(0)), A);
+        assert_eq!(RistrettoPoint::conditional_select(&A, &B, Choice::from(1)), B);
+
+        let mut P = A;
+        P.conditional_assign(&B, Choice::from(1));
+        assert_eq!(P, B);
     }
-}
 
-impl Zeroize for RistrettoPoint {
-    fn zeroize(&mut self) {
-        self.0.zeroize();
+    #[test]
+    fn all_distinct() {
+        use sha2::Sha512;
+
+        let G: Vec<RistrettoPoint> = (0u8..8)
+            .map(|i| RistrettoPoint::hash_from_bytes::<Sha512>(&[i]))
+            .collect();
+        assert!(RistrettoPoint::all_distinct(&G));
+        assert!(RistrettoPoint::all_distinct(&[]));
+
+        let mut duplicated = G.clone();
+        duplicated.push(G[3]);
+        assert!(!RistrettoPoint::all_distinct(&duplicated));
+
+        let mut with_identity = G.clone();
+        with_identity[5] = RistrettoPoint::identity();
+        assert!(!RistrettoPoint::all_distinct(&with_identity));
     }
-}
 
-// ------------------------------------------------------------------------
-// Tests
-// ------------------------------------------------------------------------
+    #[test]
+    fn expand_message_xmd_sha512_vector() {
+        use sha2::Sha512;
+
+        // From RFC 9380, appendix K.3.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let expected = hex_encoding::decode_32(
+            "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba"
+        ).unwrap();
+
+        let mut out = [0u8; 32];
+        expand_message_xmd::<Sha512>(b"", dst, &mut out);
+        assert_eq!(out, expected);
+    }
 
-#[cfg(test)]
-mod test {
-    use rand_core::OsRng;
+    #[test]
+    fn expand_message_xmd_sha512_multi_block_vector() {
+        use sha2::Sha512;
+
+        // From RFC 9380, appendix K.3, with len_in_bytes = 0x80, so
+        // that the b_i chaining across two digest blocks is exercised.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let expected = [
+            "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921",
+            "b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e",
+            "0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7e",
+            "b00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+        ];
 
-    use scalar::Scalar;
-    use constants;
-    use edwards::CompressedEdwardsY;
-    use traits::{Identity};
-    use super::*;
+        let mut out = [0u8; 0x80];
+        expand_message_xmd::<Sha512>(b"", dst, &mut out);
+        for (chunk, hex) in out.chunks(32).zip(expected.iter()) {
+            assert_eq!(chunk, &hex_encoding::decode_32(hex).unwrap()[..]);
+        }
+    }
 
     #[test]
-    #[cfg(feature = "serde")]
-    fn serde_bincode_basepoint_roundtrip() {
-        use bincode;
+    fn hash_to_curve_uses_expanded_bytes() {
+        use sha2::Sha512;
+
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+        // expand_message_xmd(b"", dst, 64), computed independently.
+        let lo = hex_encoding::decode_32(
+            "0d3b043d43ae0295e4de2732fa75616ee6a1d951ab8e5b8e3686812fe28e301b"
+        ).unwrap();
+        let hi = hex_encoding::decode_32(
+            "c71daafdb75b1675b937b501bd905c613459f10aaca92fed5d947c19874a978d"
+        ).unwrap();
+        let mut uniform_bytes = [0u8; 64];
+        uniform_bytes[..32].copy_from_slice(&lo);
+        uniform_bytes[32..].copy_from_slice(&hi);
 
-        let encoded = bincode::serialize(&constants::RISTRETTO_BASEPOINT_POINT).unwrap();
-        let enc_compressed = bincode::serialize(&constants::RISTRETTO_BASEPOINT_COMPRESSED).unwrap();
-        assert_eq!(encoded, enc_compressed);
+        let P = RistrettoPoint::hash_to_curve::<Sha512>(b"", dst);
+        assert_eq!(P, RistrettoPoint::from_uniform_bytes(&uniform_bytes));
+        assert_ne!(P, RistrettoPoint::hash_to_curve::<Sha512>(b"abc", dst));
+    }
 
-        // Check that the encoding is 32 bytes exactly
-        assert_eq!(encoded.len(), 32);
+    #[test]
+    fn hash_to_curve_regression() {
+        use sha2::Sha512;
+
+        // This is this crate's own output, pinned so that any change
+        // to it is caught; it is not a published vector.  The parts
+        // are checked against published vectors: `expand_message_xmd`
+        // against RFC 9380 above, and `from_uniform_bytes` against
+        // RFC 9496 in `from_uniform_bytes_vs_rfc9496`.  The 64
+        // expanded bytes are those checked in
+        // `hash_to_curve_uses_expanded_bytes`.
+        let dst = b"QUUX-V01-CS02-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+        let expected = CompressedRistretto(hex_encoding::decode_32(
+            "bed61e1ee1966329962880e236dfdc83afd52fd1ce116f64fb806f1e8acea926"
+        ).unwrap());
+
+        assert_eq!(RistrettoPoint::hash_to_curve::<Sha512>(b"", dst).compress(), expected);
+    }
 
-        let dec_uncompressed: RistrettoPoint = bincode::deserialize(&encoded).unwrap();
-        let dec_compressed: CompressedRistretto = bincode::deserialize(&encoded).unwrap();
+    #[test]
+    fn hash_to_point_and_base_scalar() {
+        use sha2::{Digest, Sha512};
 
-        assert_eq!(dec_uncompressed, constants::RISTRETTO_BASEPOINT_POINT);
-        assert_eq!(dec_compressed, constants::RISTRETTO_BASEPOINT_COMPRESSED);
+        let (H, s) = RistrettoPoint::hash_to_point_and_base_scalar::<Sha512>(b"input");
+        assert_eq!((H, s), RistrettoPoint::hash_to_point_and_base_scalar::<Sha512>(b"input"));
 
-        // Check that the encoding itself matches the usual one
-        let raw_bytes = constants::RISTRETTO_BASEPOINT_COMPRESSED.as_bytes();
-        let bp: RistrettoPoint = bincode::deserialize(raw_bytes).unwrap();
-        assert_eq!(bp, constants::RISTRETTO_BASEPOINT_POINT);
+        let (H_2, s_2) = RistrettoPoint::hash_to_point_and_base_scalar::<Sha512>(b"other input");
+        assert_ne!(H, H_2);
+        assert_ne!(s, s_2);
+
+        let point_hash = Sha512::new().chain(b"curve25519-dalek h2p point").chain(b"input");
+        let scalar_hash = Sha512::new().chain(b"curve25519-dalek h2p scalar").chain(b"input");
+        assert_eq!(H, RistrettoPoint::from_hash(point_hash));
+        assert_eq!(s, Scalar::from_hash(scalar_hash));
+        assert_ne!(H, RistrettoPoint::hash_from_bytes::<Sha512>(b"input"));
     }
 
     #[test]
-    fn scalarmult_ristrettopoint_works_both_ways() {
+    #[cfg(feature = "sha2")]
+    fn sha512_ciphersuite() {
+        use sha2::{Digest, Sha512};
+
+        let domain = Sha512Ristretto::DOMAIN;
+        let prefixed = |tag: u8| {
+            Sha512::new()
+                .chain((domain.len() as u64).to_le_bytes())
+                .chain(domain)
+                .chain([tag])
+                .chain(b"msg")
+        };
+
+        let s = Sha512Ristretto::hash_to_scalar(b"msg");
+        assert_eq!(s, Scalar::from_hash(prefixed(0)));
+        assert_ne!(s, Sha512Ristretto::hash_to_scalar(b"msh"));
+
+        let P = Sha512Ristretto::hash_to_group(b"msg");
+        assert_eq!(P, RistrettoPoint::from_hash(prefixed(1)));
+        assert_ne!(P, Sha512Ristretto::hash_to_group(b"msh"));
+
+        assert_eq!(Sha512Ristretto::generator(), constants::RISTRETTO_BASEPOINT_POINT);
+    }
+
+    #[test]
+    fn conditional_negate() {
         let P = constants::RISTRETTO_BASEPOINT_POINT;
-        let s = Scalar::from(999u64);
 
-        let P1 = &P * &s;
-        let P2 = &s * &P;
+        let mut Q = P;
+        Q.conditional_negate(Choice::from(1));
+        assert_eq!(Q, -P);
 
-        assert!(P1.compress().as_bytes() == P2.compress().as_bytes());
+        let mut R = P;
+        R.conditional_negate(Choice::from(0));
+        assert_eq!(R, P);
     }
 
     #[test]
-    fn impl_sum() {
+    fn compress_into_matches_compress() {
+        let mut rng = OsRng;
+        for _ in 0..8 {
+            let P = RistrettoPoint::random(&mut rng);
+            let mut out = [0u8; 32];
+            P.compress_into(&mut out);
+            assert_eq!(&out, P.compress().as_bytes());
+        }
 
-        // Test that sum works for non-empty iterators
-        let BASE = constants::RISTRETTO_BASEPOINT_POINT;
+        let mut out = [0u8; 32];
+        constants::RISTRETTO_BASEPOINT_POINT.compress_into(&mut out);
+        assert_eq!(&out, constants::RISTRETTO_BASEPOINT_COMPRESSED.as_bytes());
+    }
 
-        let s1 = Scalar::from(999u64);
-        let P1 = &BASE * &s1;
+    #[test]
+    fn compressed_hex_roundtrip() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng).compress();
+        assert_eq!(CompressedRistretto::from_hex(&P.to_hex()), Some(P));
 
-        let s2 = Scalar::from(333u64);
-        let P2 = &BASE * &s2;
+        let B = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        let B_hex = "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76";
+        assert_eq!(B.to_hex(), B_hex);
+        assert_eq!(CompressedRistretto::from_hex(&B_hex.to_uppercase()), Some(B));
 
-        let vec = vec![P1.clone(), P2.clone()];
-        let sum: RistrettoPoint = vec.iter().sum();
+        assert_eq!(CompressedRistretto::from_hex(&B_hex[..62]), None);
+        assert_eq!(CompressedRistretto::from_hex(&B_hex.replace("e", "g")), None);
+    }
 
-        assert_eq!(sum, P1 + P2);
+    #[test]
+    fn compressed_encoded_size() {
+        let buf = [0u8; CompressedRistretto::ENCODED_SIZE];
+        assert_eq!(CompressedRistretto::from_slice(&buf), CompressedRistretto::identity());
+    }
 
-        // Test that sum works for the empty iterator
-        let empty_vector: Vec<RistrettoPoint> = vec![];
-        let sum: RistrettoPoint = empty_vector.iter().sum();
+    #[test]
+    fn compressed_ct_eq() {
+        let P = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        let mut Q = P;
+        assert_eq!(P.ct_eq(&Q).unwrap_u8(), 1u8);
 
-        assert_eq!(sum, RistrettoPoint::identity());
+        Q.0[31] ^= 1;
+        assert_eq!(P.ct_eq(&Q).unwrap_u8(), 0u8);
+    }
 
-        // Test that sum works on owning iterators
-        let s = Scalar::from(2u64);
-        let mapped = vec.iter().map(|x| x * s);
-        let sum: RistrettoPoint = mapped.sum();
+    #[test]
+    fn compressed_neg_and_sub() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        let C = constants::RISTRETTO_BASEPOINT_COMPRESSED;
 
-        assert_eq!(sum, &P1 * &s + &P2 * &s);
+        assert_eq!((-C).and_then(|negC| negC.decompress()), Some(-B));
+
+        let B2 = (B + B).compress();
+        assert_eq!(B2 - C, Some(C));
+        assert_eq!(C - C, Some(CompressedRistretto::identity()));
+
+        // Malformed inputs cannot be negated or subtracted.
+        let bad = CompressedRistretto(constants::EDWARDS_D.to_bytes());
+        assert!((-bad).is_none());
+        assert!((C - bad).is_none());
+        assert!((bad - C).is_none());
+    }
+
+    #[test]
+    fn from_compressed_edwards() {
+        let mut rng = OsRng;
+        for _ in 0..16 {
+            let P = RistrettoPoint::random(&mut rng);
+            for R in P.coset4().iter() {
+                let Q = RistrettoPoint::from_compressed_edwards(&R.compress()).unwrap();
+                assert_eq!(P, Q);
+            }
+
+            // A point with an odd torsion component is not in 2E
+            let R = &P.0 + &constants::EIGHT_TORSION[1];
+            assert!(RistrettoPoint::from_compressed_edwards(&R.compress()).is_none());
+        }
+
+        // Points of order 8 must be rejected
+        for i in (0..8).filter(|i| i % 2 == 1) {
+            let T = constants::EIGHT_TORSION[i].compress();
+            assert!(RistrettoPoint::from_compressed_edwards(&T).is_none());
+        }
+
+        // The points of E[4] represent the identity, but are of small
+        // order, so they must be rejected too
+        let order_2 = constants::EIGHT_TORSION[4];
+        let order_4 = [constants::EIGHT_TORSION[2], constants::EIGHT_TORSION[6]];
+        assert!(RistrettoPoint::from_compressed_edwards(&order_2.compress()).is_none());
+        for T in order_4.iter() {
+            assert!(RistrettoPoint::from_compressed_edwards(&T.compress()).is_none());
+        }
+        let identity = constants::EIGHT_TORSION[0].compress();
+        assert!(RistrettoPoint::from_compressed_edwards(&identity).is_none());
     }
 
     #[test]
@@ -1242,6 +3821,210 @@ mod test {
         }
     }
 
+    #[test]
+    fn to_bytes_and_from_bytes_roundtrip() {
+        let mut rng = OsRng;
+        for _ in 0..16 {
+            let P = RistrettoPoint::random(&mut rng);
+            let bytes = P.to_bytes();
+            assert_eq!(bytes, P.compress().to_bytes());
+            assert_eq!(RistrettoPoint::from_bytes(&bytes), Some(P));
+        }
+
+        let mut invalid = constants::RISTRETTO_BASEPOINT_COMPRESSED.to_bytes();
+        invalid[0] ^= 1;
+        assert_eq!(RistrettoPoint::from_bytes(&invalid), None);
+    }
+
+    #[test]
+    fn mul_sub_matches_mul_then_sub() {
+        let mut rng = OsRng;
+        for _ in 0..8 {
+            let s = Scalar::random(&mut rng);
+            let base = RistrettoPoint::random(&mut rng);
+            let sub = RistrettoPoint::random(&mut rng);
+            assert_eq!(RistrettoPoint::mul_sub(&s, &base, &sub), &(&s * &base) - &sub);
+        }
+
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        assert_eq!(RistrettoPoint::mul_sub(&Scalar::one(), &B, &B), RistrettoPoint::identity());
+    }
+
+    #[test]
+    fn random_batch_distinct() {
+        let mut rng = OsRng;
+        let points = RistrettoPoint::random_batch(&mut rng, 32);
+        assert_eq!(points.len(), 32);
+        assert!(RistrettoPoint::all_distinct(&points));
+        assert!(RistrettoPoint::random_batch(&mut rng, 0).is_empty());
+
+        // A seeded RNG reproduces the same batch.
+        use rand_core::SeedableRng;
+        let batch_1 = RistrettoPoint::random_batch(&mut rand::rngs::StdRng::seed_from_u64(7), 4);
+        let batch_2 = RistrettoPoint::random_batch(&mut rand::rngs::StdRng::seed_from_u64(7), 4);
+        assert_eq!(batch_1, batch_2);
+    }
+
+    #[test]
+    fn vartime_verify_dleq_matches_naive() {
+        let mut rng = OsRng;
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        for _ in 0..8 {
+            let s = Scalar::random(&mut rng);
+            let c = Scalar::random(&mut rng);
+            let A = RistrettoPoint::random(&mut rng);
+            assert_eq!(RistrettoPoint::vartime_verify_dleq(&s, &c, &A), &(&s * &B) - &(&c * &A));
+        }
+
+        // A valid Schnorr proof of knowledge of a = log_B(A).
+        let a = Scalar::random(&mut rng);
+        let r = Scalar::random(&mut rng);
+        let c = Scalar::random(&mut rng);
+        let R = &r * &constants::RISTRETTO_BASEPOINT_TABLE;
+        let A = &a * &constants::RISTRETTO_BASEPOINT_TABLE;
+        assert_eq!(RistrettoPoint::vartime_verify_dleq(&(r + c * a), &c, &A), R);
+    }
+
+    #[test]
+    fn conditional_select_from() {
+        let mut rng = OsRng;
+        let points: Vec<RistrettoPoint> = (0..4).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+        assert_eq!(RistrettoPoint::conditional_select_from(&points, 2, true), points[2]);
+        assert_eq!(RistrettoPoint::conditional_select_from(&points, 2, false), points[2]);
+        for i in 0..4 {
+            assert_eq!(RistrettoPoint::conditional_select_from(&points, i, true), points[i]);
+        }
+        assert_eq!(
+            RistrettoPoint::conditional_select_from(&points, 4, true),
+            RistrettoPoint::identity()
+        );
+    }
+
+    #[test]
+    fn add_compressed() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+
+        assert_eq!(B.add_compressed(&CompressedRistretto::identity()), Some(B));
+        assert_eq!(B.add_compressed(&B.compress()), Some(B + B));
+
+        let mut invalid = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        invalid.0[0] ^= 1;
+        assert_eq!(B.add_compressed(&invalid), None);
+    }
+
+    #[test]
+    fn equals_compressed() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+
+        assert!(B.equals_compressed(&constants::RISTRETTO_BASEPOINT_COMPRESSED));
+        assert!(!B.equals_compressed(&(B + B).compress()));
+        assert!(!B.equals_compressed(&CompressedRistretto::identity()));
+        assert!(RistrettoPoint::identity().equals_compressed(&CompressedRistretto::identity()));
+    }
+
+    #[test]
+    fn absorb_into_transcript() {
+        use sha2::{Digest, Sha512};
+
+        let P = constants::RISTRETTO_BASEPOINT_POINT * Scalar::from(7u64);
+
+        let mut h_1 = Sha512::new();
+        let mut h_2 = Sha512::new();
+        P.absorb_into(&mut h_1);
+        P.absorb_into(&mut h_2);
+        assert_eq!(h_1.clone().finalize(), h_2.finalize());
+
+        let mut expected = Sha512::new();
+        expected.update(b"P");
+        expected.update(32u64.to_le_bytes());
+        expected.update(P.compress().as_bytes());
+        assert_eq!(h_1.finalize(), expected.finalize());
+    }
+
+    #[test]
+    fn cached_point() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let Q = RistrettoPoint::random(&mut rng);
+
+        let cached_P = CachedRistrettoPoint::from(P);
+        assert_eq!(cached_P.compressed(), &P.compress());
+        assert_eq!(*cached_P, P);
+        assert_eq!(cached_P.point(), &P);
+
+        // Equal points have equal caches, however they were computed.
+        assert_eq!(cached_P, CachedRistrettoPoint::from(P + Q - Q));
+        assert_ne!(cached_P, CachedRistrettoPoint::from(Q));
+        assert_eq!(cached_P.ct_eq(&CachedRistrettoPoint::from(Q)).unwrap_u8(), 0u8);
+
+        // Point methods are available through Deref.
+        assert_eq!(cached_P.compress(), P.compress());
+        assert_eq!(*cached_P + Q, P + Q);
+    }
+
+    #[test]
+    fn compressed_ord_is_lexicographic() {
+        let mut low = CompressedRistretto::identity();
+        low.0[31] = 1;
+        let mut high = CompressedRistretto::identity();
+        high.0[0] = 1;
+
+        let mut encodings = vec![
+            constants::RISTRETTO_BASEPOINT_COMPRESSED,
+            high,
+            low,
+            CompressedRistretto::identity(),
+        ];
+        encodings.sort();
+
+        assert_eq!(
+            encodings,
+            vec![
+                CompressedRistretto::identity(),
+                low,
+                high,
+                constants::RISTRETTO_BASEPOINT_COMPRESSED,
+            ]
+        );
+        assert!(low < high);
+    }
+
+    #[test]
+    fn compressed_all_equal() {
+        let B = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        let B2 = (constants::RISTRETTO_BASEPOINT_POINT * Scalar::from(2u64)).compress();
+
+        assert_eq!(CompressedRistretto::all_equal(&[]), 1u8);
+        assert_eq!(CompressedRistretto::all_equal(&[B]), 1u8);
+        assert_eq!(CompressedRistretto::all_equal(&[B; 5]), 1u8);
+
+        for i in 0..5 {
+            let mut points = [B; 5];
+            points[i] = B2;
+            assert_eq!(CompressedRistretto::all_equal(&points), 0u8);
+        }
+
+        let mut flipped = B;
+        flipped.0[31] ^= 0x40;
+        assert_eq!(CompressedRistretto::all_equal(&[B, B, flipped]), 0u8);
+    }
+
+    #[test]
+    fn decompress_strict() {
+        let mut P = RistrettoPoint::identity();
+        for _ in 0..16 {
+            let encoding = P.compress();
+            assert_eq!(encoding.decompress_strict(), Some(P));
+            P += constants::RISTRETTO_BASEPOINT_POINT;
+        }
+
+        let mut mutated = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        mutated.0[0] ^= 1;
+        assert_eq!(mutated.decompress_strict(), None);
+        assert_eq!(mutated.decompress(), None);
+    }
+
     #[test]
     fn four_torsion_basepoint() {
         let bp = constants::RISTRETTO_BASEPOINT_POINT;
@@ -1313,6 +4096,118 @@ mod test {
         }
     }
 
+    // The ristretto.sage Elligator vectors from
+    // `elligator_vs_ristretto_sage`, reused by the test of the public
+    // `from_elligator_single` below.
+    const ELLIGATOR_SAGE_INPUTS: [[u8;32]; 16] = [
+        [184, 249, 135, 49, 253, 123, 89, 113, 67, 160, 6, 239, 7, 105, 211, 41, 192, 249, 185, 57, 9, 102, 70, 198, 15, 127, 7, 26, 160, 102, 134, 71],
+        [229, 14, 241, 227, 75, 9, 118, 60, 128, 153, 226, 21, 183, 217, 91, 136, 98, 0, 231, 156, 124, 77, 82, 139, 142, 134, 164, 169, 169, 62, 250, 52],
+        [115, 109, 36, 220, 180, 223, 99, 6, 204, 169, 19, 29, 169, 68, 84, 23, 21, 109, 189, 149, 127, 205, 91, 102, 172, 35, 112, 35, 134, 69, 186, 34],
+        [16, 49, 96, 107, 171, 199, 164, 9, 129, 16, 64, 62, 241, 63, 132, 173, 209, 160, 112, 215, 105, 50, 157, 81, 253, 105, 1, 154, 229, 25, 120, 83],
+        [156, 131, 161, 162, 236, 251, 5, 187, 167, 171, 17, 178, 148, 210, 90, 207, 86, 21, 79, 161, 167, 215, 234, 1, 136, 242, 182, 248, 38, 85, 79, 86],
+        [251, 177, 124, 54, 18, 101, 75, 235, 245, 186, 19, 46, 133, 157, 229, 64, 10, 136, 181, 185, 78, 144, 254, 167, 137, 49, 107, 10, 61, 10, 21, 25],
+        [232, 193, 20, 68, 240, 77, 186, 77, 183, 40, 44, 86, 150, 31, 198, 212, 76, 81, 3, 217, 197, 8, 126, 128, 126, 152, 164, 208, 153, 44, 189, 77],
+        [173, 229, 149, 177, 37, 230, 30, 69, 61, 56, 172, 190, 219, 115, 167, 194, 71, 134, 59, 75, 28, 244, 118, 26, 162, 97, 64, 16, 15, 189, 30, 64],
+        [106, 71, 61, 107, 250, 117, 42, 151, 91, 202, 212, 100, 52, 188, 190, 21, 125, 218, 31, 18, 253, 241, 160, 133, 57, 242, 3, 164, 189, 68, 111, 75],
+        [112, 204, 182, 90, 220, 198, 120, 73, 173, 107, 193, 17, 227, 40, 162, 36, 150, 141, 235, 55, 172, 183, 12, 39, 194, 136, 43, 153, 244, 118, 91, 89],
+        [111, 24, 203, 123, 254, 189, 11, 162, 51, 196, 163, 136, 204, 143, 10, 222, 33, 112, 81, 205, 34, 35, 8, 66, 90, 6, 164, 58, 170, 177, 34, 25],
+        [225, 183, 30, 52, 236, 82, 6, 183, 109, 25, 227, 181, 25, 82, 41, 193, 80, 77, 161, 80, 242, 203, 79, 204, 136, 245, 131, 110, 237, 106, 3, 58],
+        [207, 246, 38, 56, 30, 86, 176, 90, 27, 200, 61, 42, 221, 27, 56, 210, 79, 178, 189, 120, 68, 193, 120, 167, 77, 185, 53, 197, 124, 128, 191, 126],
+        [1, 136, 215, 80, 240, 46, 63, 147, 16, 244, 230, 207, 82, 189, 74, 50, 106, 169, 138, 86, 30, 131, 214, 202, 166, 125, 251, 228, 98, 24, 36, 21],
+        [210, 207, 228, 56, 155, 116, 207, 54, 84, 195, 251, 215, 249, 199, 116, 75, 109, 239, 196, 251, 194, 246, 252, 228, 70, 146, 156, 35, 25, 39, 241, 4],
+        [34, 116, 123, 9, 8, 40, 93, 189, 9, 103, 57, 103, 66, 227, 3, 2, 157, 107, 134, 219, 202, 74, 230, 154, 78, 107, 219, 195, 214, 14, 84, 80],
+    ];
+
+    const ELLIGATOR_SAGE_IMAGES: [CompressedRistretto; 16] = [
+        CompressedRistretto([176, 157, 237, 97, 66, 29, 140, 166, 168, 94, 26, 157, 212, 216, 229, 160, 195, 246, 232, 239, 169, 112, 63, 193, 64, 32, 152, 69, 11, 190, 246, 86]),
+        CompressedRistretto([234, 141, 77, 203, 181, 225, 250, 74, 171, 62, 15, 118, 78, 212, 150, 19, 131, 14, 188, 238, 194, 244, 141, 138, 166, 162, 83, 122, 228, 201, 19, 26]),
+        CompressedRistretto([232, 231, 51, 92, 5, 168, 80, 36, 173, 179, 104, 68, 186, 149, 68, 40, 140, 170, 27, 103, 99, 140, 21, 242, 43, 62, 250, 134, 208, 255, 61, 89]),
+        CompressedRistretto([208, 120, 140, 129, 177, 179, 237, 159, 252, 160, 28, 13, 206, 5, 211, 241, 192, 218, 1, 97, 130, 241, 20, 169, 119, 46, 246, 29, 79, 80, 77, 84]),
+        CompressedRistretto([202, 11, 236, 145, 58, 12, 181, 157, 209, 6, 213, 88, 75, 147, 11, 119, 191, 139, 47, 142, 33, 36, 153, 193, 223, 183, 178, 8, 205, 120, 248, 110]),
+        CompressedRistretto([26, 66, 231, 67, 203, 175, 116, 130, 32, 136, 62, 253, 215, 46, 5, 214, 166, 248, 108, 237, 216, 71, 244, 173, 72, 133, 82, 6, 143, 240, 104, 41]),
+        CompressedRistretto([40, 157, 102, 96, 201, 223, 200, 197, 150, 181, 106, 83, 103, 126, 143, 33, 145, 230, 78, 6, 171, 146, 210, 143, 112, 5, 245, 23, 183, 138, 18, 120]),
+        CompressedRistretto([220, 37, 27, 203, 239, 196, 176, 131, 37, 66, 188, 243, 185, 250, 113, 23, 167, 211, 154, 243, 168, 215, 54, 171, 159, 36, 195, 81, 13, 150, 43, 43]),
+        CompressedRistretto([232, 121, 176, 222, 183, 196, 159, 90, 238, 193, 105, 52, 101, 167, 244, 170, 121, 114, 196, 6, 67, 152, 80, 185, 221, 7, 83, 105, 176, 208, 224, 121]),
+        CompressedRistretto([226, 181, 183, 52, 241, 163, 61, 179, 221, 207, 220, 73, 245, 242, 25, 236, 67, 84, 179, 222, 167, 62, 167, 182, 32, 9, 92, 30, 165, 127, 204, 68]),
+        CompressedRistretto([226, 119, 16, 242, 200, 139, 240, 87, 11, 222, 92, 146, 156, 243, 46, 119, 65, 59, 1, 248, 92, 183, 50, 175, 87, 40, 206, 53, 208, 220, 148, 13]),
+        CompressedRistretto([70, 240, 79, 112, 54, 157, 228, 146, 74, 122, 216, 88, 232, 62, 158, 13, 14, 146, 115, 117, 176, 222, 90, 225, 244, 23, 94, 190, 150, 7, 136, 96]),
+        CompressedRistretto([22, 71, 241, 103, 45, 193, 195, 144, 183, 101, 154, 50, 39, 68, 49, 110, 51, 44, 62, 0, 229, 113, 72, 81, 168, 29, 73, 106, 102, 40, 132, 24]),
+        CompressedRistretto([196, 133, 107, 11, 130, 105, 74, 33, 204, 171, 133, 221, 174, 193, 241, 36, 38, 179, 196, 107, 219, 185, 181, 253, 228, 47, 155, 42, 231, 73, 41, 78]),
+        CompressedRistretto([58, 255, 225, 197, 115, 208, 160, 143, 39, 197, 82, 69, 143, 235, 92, 170, 74, 40, 57, 11, 171, 227, 26, 185, 217, 207, 90, 185, 197, 190, 35, 60]),
+        CompressedRistretto([88, 43, 92, 118, 223, 136, 105, 145, 238, 186, 115, 8, 214, 112, 153, 253, 38, 108, 205, 230, 157, 130, 11, 66, 101, 85, 253, 110, 110, 14, 148, 112]),
+    ];
+
+    #[test]
+    fn from_elligator_single_vs_ristretto_sage() {
+        for i in 0..16 {
+            let P = RistrettoPoint::from_elligator_single(&ELLIGATOR_SAGE_INPUTS[i]);
+            assert_eq!(P.compress(), ELLIGATOR_SAGE_IMAGES[i]);
+        }
+    }
+
+    #[test]
+    fn from_uniform_bytes_vs_rfc9496() {
+        // The one-way map test vectors from RFC 9496, Appendix A.3:
+        // the two 32-byte halves of the 64-byte input, then the
+        // encoding of the output.
+        let vectors: [(&str, &str, &str); 11] = [
+            ("5d1be09e3d0c82fc538112490e35701979d99e06ca3e2b5b54bffe8b4dc772c1",
+             "4d98b696a1bbfb5ca32c436cc61c16563790306c79eaca7705668b47dffe5bb6",
+             "3066f82a1a747d45120d1740f14358531a8f04bbffe6a819f86dfe50f44a0a46"),
+            ("f116b34b8f17ceb56e8732a60d913dd10cce47a6d53bee9204be8b44f6678b27",
+             "0102a56902e2488c46120e9276cfe54638286b9e4b3cdb470b542d46c2068d38",
+             "f26e5b6f7d362d2d2a94c5d0e7602cb4773c95a2e5c31a64f133189fa76ed61b"),
+            ("8422e1bbdaab52938b81fd602effb6f89110e1e57208ad12d9ad767e2e25510c",
+             "27140775f9337088b982d83d7fcf0b2fa1edffe51952cbe7365e95c86eaf325c",
+             "006ccd2a9e6867e6a2c5cea83d3302cc9de128dd2a9a57dd8ee7b9d7ffe02826"),
+            ("ac22415129b61427bf464e17baee8db65940c233b98afce8d17c57beeb7876c2",
+             "150d15af1cb1fb824bbd14955f2b57d08d388aab431a391cfc33d5bafb5dbbaf",
+             "f8f0c87cf237953c5890aec3998169005dae3eca1fbb04548c635953c817f92a"),
+            ("165d697a1ef3d5cf3c38565beefcf88c0f282b8e7dbd28544c483432f1cec767",
+             "5debea8ebb4e5fe7d6f6e5db15f15587ac4d4d4a1de7191e0c1ca6664abcc413",
+             "ae81e7dedf20a497e10c304a765c1767a42d6e06029758d2d7e8ef7cc4c41179"),
+            ("a836e6c9a9ca9f1e8d486273ad56a78c70cf18f0ce10abb1c7172ddd605d7fd2",
+             "979854f47ae1ccf204a33102095b4200e5befc0465accc263175485f0e17ea5c",
+             "e2705652ff9f5e44d3e841bf1c251cf7dddb77d140870d1ab2ed64f1a9ce8628"),
+            ("2cdc11eaeb95daf01189417cdddbf95952993aa9cb9c640eb5058d09702c7462",
+             "2c9965a697a3b345ec24ee56335b556e677b30e6f90ac77d781064f866a3c982",
+             "80bd07262511cdde4863f8a7434cef696750681cb9510eea557088f76d9e5065"),
+            // Inputs that all map to the same output.
+            ("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+             "1200000000000000000000000000000000000000000000000000000000000000",
+             "304282791023b73128d277bdcb5c7746ef2eac08dde9f2983379cb8e5ef0517f"),
+            ("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+             "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+             "304282791023b73128d277bdcb5c7746ef2eac08dde9f2983379cb8e5ef0517f"),
+            ("0000000000000000000000000000000000000000000000000000000000000080",
+             "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+             "304282791023b73128d277bdcb5c7746ef2eac08dde9f2983379cb8e5ef0517f"),
+            ("0000000000000000000000000000000000000000000000000000000000000000",
+             "1200000000000000000000000000000000000000000000000000000000000080",
+             "304282791023b73128d277bdcb5c7746ef2eac08dde9f2983379cb8e5ef0517f"),
+        ];
+        for &(lo, hi, output) in vectors.iter() {
+            let mut bytes = [0u8; 64];
+            bytes[..32].copy_from_slice(&hex_encoding::decode_32(lo).unwrap());
+            bytes[32..].copy_from_slice(&hex_encoding::decode_32(hi).unwrap());
+            let expected = CompressedRistretto(hex_encoding::decode_32(output).unwrap());
+
+            assert_eq!(RistrettoPoint::from_uniform_bytes(&bytes).compress(), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn sample_through_distribution() {
+        use rand::Rng;
+        use rand::distributions::Standard;
+
+        let mut rng = OsRng;
+        let points: Vec<RistrettoPoint> = rng.sample_iter(Standard).take(4).collect();
+        assert_eq!(points.len(), 4);
+        assert_ne!(points[0], points[1]);
+    }
+
     #[test]
     fn random_roundtrip() {
         let mut rng = OsRng;
@@ -1381,4 +4276,144 @@ mod test {
         assert_eq!(P.compress(), R.compress());
         assert_eq!(Q.compress(), R.compress());
     }
+
+    #[test]
+    fn from_hash_with_absorbed_data() {
+        use sha2::{Digest, Sha512};
+
+        let mut hasher = Sha512::new();
+        hasher.update(b"Ristretto is traditionally a short shot of espresso coffee ");
+        hasher.update(b"made with the normal amount of ground coffee but extracted with about half the amount of water");
+        let P = RistrettoPoint::from_hash(hasher);
+
+        let Q = RistrettoPoint::hash_from_bytes::<Sha512>(
+            b"Ristretto is traditionally a short shot of espresso coffee \
+              made with the normal amount of ground coffee but extracted with about half the amount of water");
+
+        assert_eq!(P, Q);
+    }
+
+    #[test]
+    fn commit_matches_separate_multiplications() {
+        let mut rng = OsRng;
+        let G = RistrettoPoint::random(&mut rng);
+        let H = RistrettoPoint::random(&mut rng);
+        for _ in 0..16 {
+            let v = Scalar::random(&mut rng);
+            let r = Scalar::random(&mut rng);
+            let C = RistrettoPoint::commit(&v, &G, &r, &H);
+            assert_eq!(C, &(v * G) + &(r * H));
+        }
+    }
+
+    #[test]
+    fn vartime_batch_mul_matches_individual_products() {
+        let mut rng = OsRng;
+        let pairs: Vec<(Scalar, RistrettoPoint)> = (0..8)
+            .map(|_| (Scalar::random(&mut rng), RistrettoPoint::random(&mut rng)))
+            .collect();
+
+        let products = RistrettoPoint::vartime_batch_mul(&pairs);
+
+        assert_eq!(products.len(), pairs.len());
+        for ((s, P), sP) in pairs.iter().zip(products.iter()) {
+            assert_eq!(*sP, s * P);
+        }
+
+        // Zero scalars and the empty batch are handled.
+        let zeros = [(Scalar::zero(), constants::RISTRETTO_BASEPOINT_POINT)];
+        assert_eq!(RistrettoPoint::vartime_batch_mul(&zeros), vec![RistrettoPoint::identity()]);
+        assert!(RistrettoPoint::vartime_batch_mul(&[]).is_empty());
+    }
+
+    #[test]
+    fn mul_base_matches_basepoint_table() {
+        let mut rng = OsRng;
+        let B = &constants::RISTRETTO_BASEPOINT_TABLE;
+        for _ in 0..16 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(RistrettoPoint::mul_base(&s), B * &s);
+        }
+    }
+
+    #[test]
+    fn conditional_add() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let Q = RistrettoPoint::random(&mut rng);
+
+        let mut acc = P;
+        acc.conditional_add(&Q, Choice::from(0));
+        assert_eq!(acc, P);
+        acc.conditional_add(&Q, Choice::from(1));
+        assert_eq!(acc, P + Q);
+    }
+
+    #[test]
+    fn multiscalar_context_matches_stateless() {
+        let mut rng = OsRng;
+        let mut context = MultiscalarContext::new();
+
+        // Vary the size so that the buffers both grow and shrink.
+        for &n in &[8, 2, 16, 0, 5] {
+            let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+            let points: Vec<RistrettoPoint> = (0..n).map(|_| RistrettoPoint::random(&mut rng)).collect();
+
+            assert_eq!(
+                context.vartime_multiscalar_mul(&scalars, &points),
+                RistrettoPoint::vartime_multiscalar_mul(&scalars, &points),
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn multiscalar_context_length_mismatch_panics() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        MultiscalarContext::new().vartime_multiscalar_mul(&[Scalar::one()], &[B, B]);
+    }
+
+    #[test]
+    fn compress_tagged_round_trip() {
+        let mut rng = OsRng;
+        for _ in 0..16 {
+            let P = RistrettoPoint::random(&mut rng);
+            let encoded = P.compress_tagged(0x42);
+            assert_eq!(encoded[0], 0x42);
+            assert_eq!(&encoded[1..], P.compress().as_bytes());
+            assert_eq!(RistrettoPoint::decompress_tagged(&encoded, 0x42), Some(P));
+        }
+    }
+
+    #[test]
+    fn decompress_tagged_rejects_wrong_tag_and_invalid_points() {
+        let B = constants::RISTRETTO_BASEPOINT_POINT;
+        let encoded = B.compress_tagged(1);
+        assert_eq!(RistrettoPoint::decompress_tagged(&encoded, 2), None);
+
+        // A correct tag does not rescue a non-canonical encoding.
+        let mut invalid = [0xffu8; 33];
+        invalid[0] = 1;
+        assert_eq!(RistrettoPoint::decompress_tagged(&invalid, 1), None);
+    }
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn group_traits() {
+        use group::{Group, GroupEncoding};
+
+        fn triple<G: Group>(P: G) -> G {
+            P.double() + P
+        }
+
+        let B = <RistrettoPoint as Group>::generator();
+        assert_eq!(triple(B), B * Scalar::from(3u64));
+        assert!(bool::from(<RistrettoPoint as Group>::identity().is_identity()));
+        assert!(!bool::from(B.is_identity()));
+
+        let encoded = GroupEncoding::to_bytes(&B);
+        assert_eq!(encoded, B.compress().to_bytes());
+        assert_eq!(<RistrettoPoint as GroupEncoding>::from_bytes(&encoded).unwrap(), B);
+        assert!(bool::from(<RistrettoPoint as GroupEncoding>::from_bytes(&[0xff; 32]).is_none()));
+    }
 }
@@ -142,7 +142,9 @@
 use core::borrow::Borrow;
 use core::cmp::{Eq, PartialEq};
 use core::fmt::Debug;
+use core::fmt::Display;
 use core::iter::{Product, Sum};
+use core::ops::Div;
 use core::ops::Index;
 use core::ops::Neg;
 use core::ops::{Add, AddAssign};
@@ -154,17 +156,30 @@ use prelude::*;
 
 use rand_core::{CryptoRng, RngCore};
 
-use digest::generic_array::typenum::U64;
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use digest::generic_array::typenum::{U32, U64};
+// Recent generic-array 0.14 releases deprecate the type in favour of 1.x,
+// which digest 0.9 cannot use.
+#[allow(deprecated)]
+use digest::generic_array::GenericArray;
 use digest::Digest;
 
 use subtle::Choice;
+use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+#[cfg(feature = "group")]
+use subtle::CtOption;
 
 use zeroize::Zeroize;
 
 use backend;
 use constants;
+use hex_encoding;
 
 /// An `UnpackedScalar` represents an element of the field GF(l), optimized for speed.
 ///
@@ -229,6 +244,76 @@ impl Scalar {
         UnpackedScalar::from_bytes_wide(input).pack()
     }
 
+    /// Construct a `Scalar` by reducing a little-endian integer of at
+    /// most 512 bits, given as a byte slice, modulo the group order
+    /// \\( \ell \\).
+    ///
+    /// Slices shorter than 64 bytes are zero-extended.
+    ///
+    /// # Return
+    ///
+    /// - `Some(s)`, where `s` is the input reduced mod \\( \ell \\),
+    ///   if `input.len() <= 64`;
+    /// - `None` if `input` is longer than 64 bytes.
+    pub fn from_bytes_mod_order_wide_slice(input: &[u8]) -> Option<Scalar> {
+        if input.len() > 64 {
+            return None;
+        }
+
+        let mut bytes = [0u8; 64];
+        bytes[..input.len()].copy_from_slice(input);
+
+        Some(Scalar::from_bytes_mod_order_wide(&bytes))
+    }
+
+    /// Construct a `Scalar` by reducing a 512-bit little-endian integer,
+    /// given as the `GenericArray` produced by a 64-byte `Digest`,
+    /// modulo the group order \\( \ell \\).
+    ///
+    /// This is the same as `from_bytes_mod_order_wide`, but takes a
+    /// digest output directly.
+    #[allow(deprecated)]
+    pub fn from_wide_bytes(bytes: GenericArray<u8, U64>) -> Scalar {
+        // generic-array only converts to [u8; 64] with its optional
+        // `more_lengths` feature, so copy into an array here.
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&bytes);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    /// Construct a `Scalar` by reducing a little-endian integer of
+    /// arbitrary length modulo the group order \\( \ell \\).
+    ///
+    /// The input is interpreted as
+    /// $$
+    ///    x = \sum\_{i} \mathtt{bytes}[i] \cdot 2\^{8i},
+    /// $$
+    /// i.e., `bytes[0]` is the least significant byte, matching the
+    /// other byte-oriented constructors in this module.  This can be
+    /// used to reduce digests whose output is not exactly 64 bytes,
+    /// such as 48- or 96-byte outputs.
+    ///
+    /// # Implementation
+    ///
+    /// The input is split into 64-byte chunks \\(x\_j\\), starting from
+    /// the least significant byte, so that
+    /// \\(x = \sum\_j x\_j 2\^{512j}\\).  Each chunk is reduced with
+    /// `from_bytes_mod_order_wide`, and the chunks are combined
+    /// using Horner's rule, starting from the most significant chunk.
+    pub fn reduce_wide(bytes: &[u8]) -> Scalar {
+        // 2^512 mod l, computed as (2^256 mod l)^2
+        let mut two_256_bytes = [0u8; 64];
+        two_256_bytes[32] = 1;
+        let two_256 = Scalar::from_bytes_mod_order_wide(&two_256_bytes);
+        let two_512 = two_256 * two_256;
+
+        bytes.chunks(64).rev().fold(Scalar::zero(), |acc, chunk| {
+            // Chunks are at most 64 bytes, so this cannot fail
+            let chunk = Scalar::from_bytes_mod_order_wide_slice(chunk).unwrap();
+            acc * two_512 + chunk
+        })
+    }
+
     /// Attempt to construct a `Scalar` from a canonical byte representation.
     ///
     /// # Return
@@ -248,6 +333,24 @@ impl Scalar {
         }
     }
 
+    /// Attempt to construct a `Scalar` from a canonical **big-endian**
+    /// byte representation.
+    ///
+    /// The other byte-oriented functions on `Scalar` use little-endian
+    /// encodings.  This is equivalent to reversing `bytes` and calling
+    /// `from_canonical_bytes`.
+    ///
+    /// # Return
+    ///
+    /// - `Some(s)`, where `s` is the `Scalar` corresponding to `bytes`,
+    ///   if `bytes` is a canonical big-endian byte representation;
+    /// - `None` otherwise.
+    pub fn from_bytes_be(bytes: &[u8; 32]) -> Option<Scalar> {
+        let mut le_bytes = *bytes;
+        le_bytes.reverse();
+        Scalar::from_canonical_bytes(le_bytes)
+    }
+
     /// Construct a `Scalar` from the low 255 bits of a 256-bit integer.
     ///
     /// This function is intended for applications like X25519 which
@@ -268,6 +371,18 @@ impl Debug for Scalar {
     }
 }
 
+/// Formats the canonical value of this `Scalar` as 64 digits of
+/// **big-endian** lowercase hex, the most significant digit first,
+/// matching `to_hex`, so that `from_hex` parses it back.
+impl Display for Scalar {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        for byte in self.reduce().bytes.iter().rev() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 impl Eq for Scalar {}
 impl PartialEq for Scalar {
     fn eq(&self, other: &Self) -> bool {
@@ -298,6 +413,12 @@ impl<'b> MulAssign<&'b Scalar> for Scalar {
 
 define_mul_assign_variants!(LHS = Scalar, RHS = Scalar);
 
+/// Multiply two scalars modulo \\( \ell \\), in constant time.
+///
+/// There is no separate fast path for a public operand: a windowed
+/// double-and-add over its digits needs hundreds of modular additions,
+/// which is several times slower than this Montgomery multiplication
+/// even for short public values.
 impl<'a, 'b> Mul<&'b Scalar> for &'a Scalar {
     type Output = Scalar;
     fn mul(self, _rhs: &'b Scalar) -> Scalar {
@@ -307,6 +428,20 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a Scalar {
 
 define_mul_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
 
+impl<'a, 'b> Div<&'b Scalar> for &'a Scalar {
+    type Output = Scalar;
+    /// Compute \\( a / b \pmod \ell \\) as `a * b.invert()`.
+    ///
+    /// A zero divisor is not rejected: since zero has no inverse, the
+    /// result is meaningless.  Use `Scalar::checked_div` when the
+    /// divisor may be zero.
+    fn div(self, _rhs: &'b Scalar) -> Scalar {
+        UnpackedScalar::mul(&self.unpack(), &_rhs.unpack().invert()).pack()
+    }
+}
+
+define_div_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
+
 impl<'b> AddAssign<&'b Scalar> for Scalar {
     fn add_assign(&mut self, _rhs: &'b Scalar) {
         *self = *self + _rhs;
@@ -333,6 +468,42 @@ impl<'a, 'b> Add<&'b Scalar> for &'a Scalar {
 
 define_add_variants!(LHS = Scalar, RHS = Scalar, Output = Scalar);
 
+/// Multiplication by a small integer.
+///
+/// This is a convenience which first constructs a `Scalar` from the
+/// `u64` and then multiplies, so the result is always reduced.
+impl<'a> Mul<u64> for &'a Scalar {
+    type Output = Scalar;
+    fn mul(self, rhs: u64) -> Scalar {
+        self * Scalar::from(rhs)
+    }
+}
+
+impl Mul<u64> for Scalar {
+    type Output = Scalar;
+    fn mul(self, rhs: u64) -> Scalar {
+        self * Scalar::from(rhs)
+    }
+}
+
+/// Addition of a small integer.
+///
+/// This is a convenience which first constructs a `Scalar` from the
+/// `u64` and then adds, so the result is always reduced.
+impl<'a> Add<u64> for &'a Scalar {
+    type Output = Scalar;
+    fn add(self, rhs: u64) -> Scalar {
+        self + Scalar::from(rhs)
+    }
+}
+
+impl Add<u64> for Scalar {
+    type Output = Scalar;
+    fn add(self, rhs: u64) -> Scalar {
+        self + Scalar::from(rhs)
+    }
+}
+
 impl<'b> SubAssign<&'b Scalar> for Scalar {
     fn sub_assign(&mut self, _rhs: &'b Scalar) {
         *self = *self - _rhs;
@@ -387,6 +558,78 @@ impl ConditionallySelectable for Scalar {
     }
 }
 
+/// A table of the multiples \\(b, 2b, \ldots, 8b\\) of a scalar \\(b\\),
+/// used to compute \\(xb\\) for \\(-8 \leq x \leq 8\\) in constant time.
+///
+/// This is the scalar analogue of the lookup tables used for
+/// point multiplication, and is useful when evaluating signed-digit
+/// expansions with secret digits.
+#[derive(Copy, Clone, Debug)]
+pub struct ScalarLookupTable(pub(crate) [Scalar; 8]);
+
+impl ScalarLookupTable {
+    /// Given \\(-8 \leq x \leq 8\\), return \\(xb\\) in constant time.
+    pub fn select(&self, x: i8) -> Scalar {
+        debug_assert!(x >= -8);
+        debug_assert!(x <= 8);
+
+        // Compute xabs = |x|
+        let xmask = x as i16 >> 7;
+        let xabs = (x as i16 + xmask) ^ xmask;
+
+        // Set t = 0 * b
+        let mut t = Scalar::zero();
+        for j in 1..9 {
+            // Copy `self.0[j-1] == j*b` onto `t` in constant time if `|x| == j`.
+            let c = (xabs as u16).ct_eq(&(j as u16));
+            t.conditional_assign(&self.0[j - 1], c);
+        }
+        // Now t == |x| * b.
+
+        let neg_mask = Choice::from((xmask & 1) as u8);
+        t.conditional_negate(neg_mask);
+        // Now t == x * b.
+
+        t
+    }
+}
+
+impl<'a> From<&'a Scalar> for ScalarLookupTable {
+    fn from(base: &'a Scalar) -> Self {
+        let mut multiples = [base.reduce(); 8];
+        for j in 0..7 {
+            multiples[j + 1] = multiples[j] + base;
+        }
+        ScalarLookupTable(multiples)
+    }
+}
+
+/// A `Scalar` in Montgomery form \\( aR \pmod \ell \\), for chains of
+/// multiplications that stay in the Montgomery domain.
+///
+/// Products and squares of `MontgomeryScalar`s each need only a single
+/// Montgomery reduction, so keeping intermediate values in this form
+/// avoids converting in and out for every operation.  Use
+/// `Scalar::to_montgomery` and `Scalar::from_montgomery` to convert.
+#[derive(Copy, Clone, Debug)]
+pub struct MontgomeryScalar(pub(crate) UnpackedScalar);
+
+impl MontgomeryScalar {
+    /// Compute the square of this `MontgomeryScalar`, in Montgomery form.
+    pub fn square(&self) -> MontgomeryScalar {
+        MontgomeryScalar(self.0.montgomery_square())
+    }
+}
+
+impl<'a, 'b> Mul<&'b MontgomeryScalar> for &'a MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn mul(self, _rhs: &'b MontgomeryScalar) -> MontgomeryScalar {
+        MontgomeryScalar(UnpackedScalar::montgomery_mul(&self.0, &_rhs.0))
+    }
+}
+
+define_mul_variants!(LHS = MontgomeryScalar, RHS = MontgomeryScalar, Output = MontgomeryScalar);
+
 #[cfg(feature = "serde")]
 use serde::{self, Serialize, Deserialize, Serializer, Deserializer};
 #[cfg(feature = "serde")]
@@ -417,7 +660,7 @@ impl<'de> Deserialize<'de> for Scalar {
             type Value = Scalar;
 
             fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                formatter.write_str("a valid point in Edwards y + sign format")
+                formatter.write_str("a canonically encoded 32-byte scalar")
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Scalar, A::Error>
@@ -429,8 +672,9 @@ impl<'de> Deserialize<'de> for Scalar {
                         .ok_or(serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
                 }
                 Scalar::from_canonical_bytes(bytes)
-                    .ok_or(serde::de::Error::custom(
-                        &"scalar was not canonically encoded"
+                    .ok_or_else(|| serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Bytes(&bytes),
+                        &"a canonically encoded scalar",
                     ))
             }
         }
@@ -439,6 +683,147 @@ impl<'de> Deserialize<'de> for Scalar {
     }
 }
 
+/// Sample a uniformly random `Scalar`, as for `Scalar::random`.
+///
+/// The `Distribution` API does not require the RNG to be a `CryptoRng`,
+/// so it is the caller's responsibility to supply a cryptographically
+/// secure one if the scalar must be secret.
+#[cfg(feature = "rand")]
+impl Distribution<Scalar> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Scalar {
+        let mut scalar_bytes = [0u8; 64];
+        rng.fill_bytes(&mut scalar_bytes);
+        Scalar::from_bytes_mod_order_wide(&scalar_bytes)
+    }
+}
+
+/// Generate uniformly random `Scalar`s for property tests, by reducing
+/// 64 arbitrary bytes, as for `Scalar::random`.
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Scalar {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<
+        proptest::arbitrary::StrategyFor<([u8; 32], [u8; 32])>,
+        fn(([u8; 32], [u8; 32])) -> Scalar,
+    >;
+
+    fn arbitrary_with(_args: ()) -> Self::Strategy {
+        use proptest::strategy::Strategy;
+
+        proptest::arbitrary::any::<([u8; 32], [u8; 32])>().prop_map(|(lo, hi)| {
+            let mut scalar_bytes = [0u8; 64];
+            scalar_bytes[..32].copy_from_slice(&lo);
+            scalar_bytes[32..].copy_from_slice(&hi);
+            Scalar::from_bytes_mod_order_wide(&scalar_bytes)
+        })
+    }
+}
+
+/// Generate uniformly random `Scalar`s for `quickcheck`, by reducing
+/// 64 arbitrary bytes, as for `Scalar::random`.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Scalar {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Scalar {
+        let mut scalar_bytes = [0u8; 64];
+        for byte in scalar_bytes.iter_mut() {
+            *byte = u8::arbitrary(g);
+        }
+        Scalar::from_bytes_mod_order_wide(&scalar_bytes)
+    }
+}
+
+/// `Scalar` is the prime field \\( \mathbb Z / \ell \mathbb Z \\), so it
+/// implements the `ff` field traits re-exported by the `group` crate.
+#[cfg(feature = "group")]
+impl group::ff::Field for Scalar {
+    const ZERO: Self = Scalar::ZERO;
+    const ONE: Self = Scalar::ONE;
+
+    fn random(mut rng: impl rand_core_06::RngCore) -> Self {
+        let mut scalar_bytes = [0u8; 64];
+        rng.fill_bytes(&mut scalar_bytes);
+        Scalar::from_bytes_mod_order_wide(&scalar_bytes)
+    }
+
+    fn square(&self) -> Self {
+        self * self
+    }
+
+    fn double(&self) -> Self {
+        self + self
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        CtOption::new(Scalar::invert(self), !self.ct_eq(&Scalar::ZERO))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        group::ff::helpers::sqrt_ratio_generic(num, div)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        let (is_square, root) = self.sqrt_ct();
+        CtOption::new(root, is_square)
+    }
+}
+
+#[cfg(feature = "group")]
+impl group::ff::PrimeField for Scalar {
+    type Repr = [u8; 32];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let candidate = Scalar { bytes: repr };
+        let is_canonical = candidate.ct_eq(&candidate.reduce());
+        CtOption::new(candidate, is_canonical)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.reduce().bytes
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.reduce().bytes[0] & 1)
+    }
+
+    const MODULUS: &'static str =
+        "0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+    const NUM_BITS: u32 = 253;
+    const CAPACITY: u32 = 252;
+    const TWO_INV: Self = constants::TWO_INV;
+    const MULTIPLICATIVE_GENERATOR: Self = Scalar {
+        bytes: [
+            2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    };
+    /// \\( \ell - 1 = 2^2 \cdot t \\) with \\( t \\) odd.
+    const S: u32 = 2;
+    /// \\( 2^t \\), a primitive fourth root of unity.
+    const ROOT_OF_UNITY: Self = Scalar {
+        bytes: [
+            0xd4, 0x07, 0xbe, 0xeb, 0xdf, 0x75, 0x87, 0xbe,
+            0xfe, 0x83, 0xce, 0x42, 0x53, 0x56, 0xf0, 0x0e,
+            0x7a, 0xc2, 0xc1, 0xab, 0x60, 0x6d, 0x3d, 0x7d,
+            0xe7, 0x81, 0x79, 0xe0, 0x10, 0x73, 0x4a, 0x09,
+        ],
+    };
+    const ROOT_OF_UNITY_INV: Self = Scalar {
+        bytes: [
+            0x19, 0xcc, 0x37, 0x71, 0x3a, 0xed, 0x8a, 0x99,
+            0xd7, 0x18, 0x29, 0x60, 0x8b, 0xa3, 0xee, 0x05,
+            0x86, 0x3d, 0x3e, 0x54, 0x9f, 0x92, 0xc2, 0x82,
+            0x18, 0x7e, 0x86, 0x1f, 0xef, 0x8c, 0xb5, 0x06,
+        ],
+    };
+    /// \\( g^{2^S} = 2^4 = 16 \\).
+    const DELTA: Self = Scalar {
+        bytes: [
+            16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    };
+}
+
 impl<T> Product<T> for Scalar
 where
     T: Borrow<Scalar>
@@ -534,6 +919,37 @@ impl From<u128> for Scalar {
     }
 }
 
+impl From<[u8; 32]> for Scalar {
+    /// Construct a `Scalar` by reducing a 256-bit little-endian integer
+    /// modulo the group order \\( \ell \\).
+    ///
+    /// This is the same as `Scalar::from_bytes_mod_order`, so the
+    /// result is always canonical.  Unlike `Scalar::from_bits`, it
+    /// never produces an unreduced `Scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s: Scalar = [0xffu8; 32].into();
+    /// assert!(s.is_canonical());
+    /// ```
+    fn from(bytes: [u8; 32]) -> Scalar {
+        Scalar::from_bytes_mod_order(bytes)
+    }
+}
+
+impl From<Scalar> for [u8; 32] {
+    /// Return the canonical little-endian byte encoding of a `Scalar`.
+    ///
+    /// A `Scalar` constructed with `from_bits` is reduced first, so
+    /// this may differ from `Scalar::to_bytes` for such scalars.
+    fn from(s: Scalar) -> [u8; 32] {
+        s.reduce().to_bytes()
+    }
+}
+
 impl Zeroize for Scalar {
     fn zeroize(&mut self) {
         self.bytes.zeroize();
@@ -602,6 +1018,40 @@ impl Scalar {
         Scalar::from_hash(hash)
     }
 
+    /// Hash a slice of bytes into a scalar, using a digest with only 32
+    /// bytes (256 bits) of output, such as SHA-256.
+    ///
+    /// Reducing a single 256-bit digest mod \\( \ell \\) would give a
+    /// noticeably biased scalar, so this computes
+    /// \\( D(\mathtt{0x00} \| \mathtt{input}) \| D(\mathtt{0x01} \| \mathtt{input}) \\)
+    /// and reduces the resulting 64 bytes, as `hash_from_bytes` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    /// use sha2::Sha256;
+    ///
+    /// # fn main() {
+    /// let msg = "To really appreciate architecture, you may even need to commit a murder";
+    /// let s = Scalar::hash_from_bytes_256::<Sha256>(msg.as_bytes());
+    /// # }
+    /// ```
+    pub fn hash_from_bytes_256<D>(input: &[u8]) -> Scalar
+        where D: Digest<OutputSize = U32> + Default
+    {
+        let mut output = [0u8; 64];
+        for (counter, half) in output.chunks_mut(32).enumerate() {
+            let mut hash = D::default();
+            hash.update([counter as u8]);
+            hash.update(input);
+            half.copy_from_slice(&hash.finalize());
+        }
+        Scalar::from_bytes_mod_order_wide(&output)
+    }
+
     /// Construct a scalar from an existing `Digest` instance.
     ///
     /// Use this instead of `hash_from_bytes` if it is more convenient
@@ -638,10 +1088,114 @@ impl Scalar {
     /// ```
     pub fn from_hash<D>(hash: D) -> Scalar
         where D: Digest<OutputSize = U64>
+    {
+        Scalar::from_wide_bytes(hash.finalize())
+    }
+
+    /// Absorb this scalar into a Fiat-Shamir transcript hash.
+    ///
+    /// This feeds `hasher` the tag byte `b'S'`, the length of the
+    /// encoding as 8 little-endian bytes, and the 32-byte canonical
+    /// encoding of the scalar, reduced mod \\( \ell \\) first.  See
+    /// `RistrettoPoint::absorb_into` for the matching point encoding.
+    pub fn absorb_into<D: Digest>(&self, hasher: &mut D) {
+        let encoding = self.reduce();
+        hasher.update([b'S']);
+        hasher.update((encoding.bytes.len() as u64).to_le_bytes());
+        hasher.update(encoding.as_bytes());
+    }
+
+    /// Derive a deterministic nonce from a secret scalar and a message.
+    ///
+    /// This computes \\( H(\mathtt{secret} \| \mathtt{message}) \bmod \ell \\),
+    /// where `secret` is encoded as its 32 canonical bytes, as in
+    /// deterministic signature schemes.  The same `(secret, message)`
+    /// pair always gives the same nonce.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes (512 bits) of output, so that the reduction mod
+    /// \\( \ell \\) gives a uniformly distributed scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let secret = Scalar::from(1234u64);
+    /// let r = Scalar::derive_nonce::<Sha512>(&secret, b"message");
+    ///
+    /// assert_eq!(r, Scalar::derive_nonce::<Sha512>(&secret, b"message"));
+    /// # }
+    /// ```
+    pub fn derive_nonce<D>(secret: &Scalar, message: &[u8]) -> Scalar
+        where D: Digest<OutputSize = U64> + Default
+    {
+        let mut hash = D::default();
+        hash.update(secret.reduce().as_bytes());
+        hash.update(message);
+        Scalar::from_hash(hash)
+    }
+
+    /// Derive `count` pseudorandom scalars from a single seed scalar.
+    ///
+    /// The `i`-th output is \\( H(\mathtt{seed} \| i) \bmod \ell \\),
+    /// where `seed` is encoded as its 32 canonical bytes and \\( i \\)
+    /// as 8 little-endian bytes, for \\( i \\) in `0..count`.  The same
+    /// seed always gives the same stream, and a shorter stream is a
+    /// prefix of a longer one.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes (512 bits) of output, as for `derive_nonce`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// extern crate sha2;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let seed = Scalar::from(1234u64);
+    /// let stream = Scalar::prf_stream::<Sha512>(&seed, 4);
+    ///
+    /// assert_eq!(stream.len(), 4);
+    /// assert_eq!(stream[..2], Scalar::prf_stream::<Sha512>(&seed, 2)[..]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prf_stream<D>(seed: &Scalar, count: usize) -> Vec<Scalar>
+        where D: Digest<OutputSize = U64> + Default
+    {
+        let seed_bytes = seed.reduce().to_bytes();
+        (0..count as u64)
+            .map(|counter| {
+                let mut hash = D::default();
+                hash.update(seed_bytes);
+                hash.update(counter.to_le_bytes());
+                Scalar::from_hash(hash)
+            })
+            .collect()
+    }
+
+    /// Construct a scalar from an existing `Digest` instance, also
+    /// returning the 64-byte digest which was reduced to produce it.
+    ///
+    /// This is intended for comparing hash-to-scalar behaviour against
+    /// other implementations, and is only available with the
+    /// `debug-internals` feature.  The returned `Scalar` is the same as
+    /// the output of `from_hash`.
+    #[cfg(feature = "debug-internals")]
+    pub fn from_hash_debug<D>(hash: D) -> (Scalar, [u8; 64])
+        where D: Digest<OutputSize = U64>
     {
         let mut output = [0u8; 64];
-        output.copy_from_slice(hash.finalize().as_slice());
-        Scalar::from_bytes_mod_order_wide(&output)
+        output.copy_from_slice(&hash.finalize());
+        (Scalar::from_bytes_mod_order_wide(&output), output)
     }
 
     /// Convert this `Scalar` to its underlying sequence of bytes.
@@ -659,47 +1213,207 @@ impl Scalar {
         self.bytes
     }
 
-    /// View the little-endian byte encoding of the integer representing this Scalar.
+    /// Encode this `Scalar` as 64 lowercase hexadecimal characters,
+    /// **big-endian**, the most significant digit first.
+    ///
+    /// This is the same order as `Display` and `from_hex_be`, and the
+    /// reverse of the little-endian `to_bytes`.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        hex_encoding::encode(&self.to_bytes_be())
+    }
+
+    /// Construct a `Scalar` from 64 **big-endian** hexadecimal
+    /// characters, as produced by `to_hex` and `Display`.
+    ///
+    /// Unlike `from_hex_be`, this requires all 64 digits and rejects
+    /// non-canonical scalars rather than reducing them.
+    ///
+    /// # Return
+    ///
+    /// - `Some(s)` if `hex` consists of exactly 64 hex digits, encoding
+    ///   a canonical scalar;
+    /// - `None` otherwise.
     ///
     /// # Example
     ///
     /// ```
     /// use curve25519_dalek::scalar::Scalar;
     ///
-    /// let s: Scalar = Scalar::zero();
+    /// let s = Scalar::from(1u64);
+    /// let hex = "0000000000000000000000000000000000000000000000000000000000000001";
     ///
-    /// assert!(s.as_bytes() == &[0u8; 32]);
+    /// assert_eq!(s.to_hex(), hex);
+    /// assert_eq!(format!("{}", s), hex);
+    /// assert_eq!(Scalar::from_hex(hex), Some(s));
     /// ```
-    pub fn as_bytes(&self) -> &[u8; 32] {
-        &self.bytes
+    pub fn from_hex(hex: &str) -> Option<Scalar> {
+        hex_encoding::decode_32(hex).and_then(|bytes| Scalar::from_bytes_be(&bytes))
     }
 
-    /// Construct the scalar \\( 0 \\).
-    pub fn zero() -> Self {
-        Scalar { bytes: [0u8; 32]}
+    /// Construct a `Scalar` from four `u64` limbs, reducing it mod
+    /// \\( \ell \\).
+    ///
+    /// The limbs are **little-endian**: `limbs[0]` holds the least
+    /// significant 64 bits, and each limb is laid out little-endian,
+    /// so this reduces the integer
+    /// \\( \sum\_i \mathtt{limbs}[i] \cdot 2\^{64i} \\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from_u64_limbs([7, 0, 0, 0]);
+    /// assert_eq!(s, Scalar::from(7u64));
+    /// assert_eq!(s.to_u64_limbs(), [7, 0, 0, 0]);
+    /// ```
+    pub fn from_u64_limbs(limbs: [u64; 4]) -> Scalar {
+        use byteorder::{ByteOrder, LittleEndian};
+        let mut bytes = [0u8; 32];
+        LittleEndian::write_u64_into(&limbs, &mut bytes);
+        Scalar::from_bytes_mod_order(bytes)
     }
 
-    /// Construct the scalar \\( 1 \\).
-    pub fn one() -> Self {
-        Scalar {
-            bytes: [
-                1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            ],
-        }
+    /// Convert this `Scalar` to four `u64` limbs.
+    ///
+    /// This is the inverse of `from_u64_limbs` for reduced scalars: the
+    /// limbs are little-endian, with `limbs[0]` holding the least
+    /// significant 64 bits of the representative.
+    pub fn to_u64_limbs(&self) -> [u64; 4] {
+        use byteorder::{ByteOrder, LittleEndian};
+        let mut limbs = [0u64; 4];
+        LittleEndian::read_u64_into(&self.bytes, &mut limbs);
+        limbs
     }
 
-    /// Given a nonzero `Scalar`, compute its multiplicative inverse.
+    /// Parse a `Scalar` from a **big-endian** hexadecimal string,
+    /// reducing it mod \\( \ell \\).
     ///
-    /// # Warning
-    ///
-    /// `self` **MUST** be nonzero.  If you cannot
-    /// *prove* that this is the case, you **SHOULD NOT USE THIS
-    /// FUNCTION**.
+    /// This accepts the integer as it would naturally be written, with
+    /// the most significant digit first and leading zeros optional, so
+    /// that test vectors and fixtures can be transcribed directly.
     ///
-    /// # Returns
+    /// # Return
     ///
-    /// The multiplicative inverse of the this `Scalar`.
+    /// - `Some(s)` if `hex` consists of between 1 and 64 hex digits;
+    /// - `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from_hex_be("11"), Some(Scalar::from(17u64)));
+    /// assert_eq!(Scalar::from_hex_be("0x11"), None);
+    /// ```
+    pub fn from_hex_be(hex: &str) -> Option<Scalar> {
+        if hex.is_empty() || hex.len() > 64 {
+            return None;
+        }
+        let mut padded = [b'0'; 64];
+        padded[64 - hex.len()..].copy_from_slice(hex.as_bytes());
+
+        let padded = core::str::from_utf8(&padded).ok()?;
+        let mut bytes = hex_encoding::decode_32(padded)?;
+        bytes.reverse();
+        Some(Scalar::from_bytes_mod_order(bytes))
+    }
+
+    /// Convert this `Scalar` to its **big-endian** byte encoding.
+    ///
+    /// This is the reverse of `to_bytes`, which is little-endian.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(1u64);
+    /// let be = s.to_bytes_be();
+    ///
+    /// assert_eq!(be[31], 1);
+    /// assert_eq!(Scalar::from_bytes_be(&be), Some(s));
+    /// ```
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.bytes;
+        bytes.reverse();
+        bytes
+    }
+
+    /// View the little-endian byte encoding of the integer representing this Scalar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s: Scalar = Scalar::zero();
+    ///
+    /// assert!(s.as_bytes() == &[0u8; 32]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.bytes
+    }
+
+    /// The length in bytes of the encoding of a `Scalar`.
+    pub const ENCODED_SIZE: usize = 32;
+
+    /// The scalar \\( 0 \\).
+    pub const ZERO: Self = Scalar { bytes: [0u8; 32]};
+
+    /// The scalar \\( 1 \\).
+    pub const ONE: Self = Scalar {
+        bytes: [
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    };
+
+    /// Construct the scalar \\( 0 \\).
+    pub fn zero() -> Self {
+        Scalar::ZERO
+    }
+
+    /// Construct the scalar \\( 1 \\).
+    pub fn one() -> Self {
+        Scalar::ONE
+    }
+
+    /// Construct the scalar \\( \ell - 1 \\), i.e. \\( -1 \pmod \ell \\).
+    ///
+    /// This is the same value as `constants::BASEPOINT_ORDER_MINUS_1`.
+    pub fn order_minus_one() -> Self {
+        constants::BASEPOINT_ORDER_MINUS_1
+    }
+
+    /// Construct the scalar \\( (\ell - 1) / 2 \\).
+    ///
+    /// Canonical scalars greater than this value are the negations of
+    /// those at most this value, so it is the boundary used when
+    /// normalizing the "sign" of a scalar.
+    pub fn half_order() -> Self {
+        Scalar {
+            bytes: [
+                0xf6, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c,
+                0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c, 0x6f, 0x0a,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+            ],
+        }
+    }
+
+    /// Given a nonzero `Scalar`, compute its multiplicative inverse.
+    ///
+    /// # Warning
+    ///
+    /// `self` **MUST** be nonzero.  If you cannot
+    /// *prove* that this is the case, you **SHOULD NOT USE THIS
+    /// FUNCTION**.
+    ///
+    /// # Returns
+    ///
+    /// The multiplicative inverse of the this `Scalar`.
     ///
     /// # Example
     ///
@@ -730,6 +1444,216 @@ impl Scalar {
         self.unpack().invert().pack()
     }
 
+    /// Compute a square root of this `Scalar` modulo \\( \ell \\), if
+    /// it has one.
+    ///
+    /// Since \\( \ell \\) is prime, a nonzero \\( a \\) has a square
+    /// root exactly when it is a quadratic residue, i.e. when
+    /// \\( a\^{(\ell - 1)/2} = 1 \\); half of the nonzero scalars are
+    /// residues.  Zero is its own square root.
+    ///
+    /// # Return
+    ///
+    /// - `Some(r)` with \\( r\^2 = a \\), if `self` is a square.  Of
+    ///   the two roots \\( \pm r \\), the one at most
+    ///   \\( (\ell - 1) / 2 \\) is returned;
+    /// - `None` otherwise.
+    ///
+    /// # Implementation
+    ///
+    /// Since \\( \ell \equiv 5 \pmod 8 \\), this uses Atkin's
+    /// algorithm: with \\( v = (2a)\^{(\ell - 5)/8} \\) and
+    /// \\( i = 2av\^2 \\), the candidate root is \\( av(i - 1) \\).  The
+    /// exponentiation uses a fixed exponent, so the time taken does
+    /// not depend on `self`, apart from the final `Option`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let four = Scalar::from(4u64);
+    ///
+    /// assert_eq!(four.sqrt(), Some(Scalar::from(2u64)));
+    /// assert_eq!(Scalar::from(2u64).sqrt(), None);
+    /// ```
+    pub fn sqrt(&self) -> Option<Scalar> {
+        let (is_square, root) = self.sqrt_ct();
+        if is_square.into() {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Compute a candidate square root of this `Scalar` in constant
+    /// time, as for `sqrt`.
+    ///
+    /// Returns `(is_square, root)`; `root` is only meaningful when
+    /// `is_square` is set.
+    fn sqrt_ct(&self) -> (Choice, Scalar) {
+        // (l - 5) / 8, little-endian.
+        const EXPONENT: [u8; 32] = [
+            0x7d, 0xba, 0x9e, 0x4b, 0x63, 0x4c, 0x02, 0xcb,
+            0x9a, 0xf3, 0x5e, 0xd4, 0x3b, 0xdf, 0x9b, 0x02,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ];
+
+        // Everything is in Montgomery form, where R is one.
+        let a = self.unpack().to_montgomery();
+        let two_a = UnpackedScalar::add(&a, &a);
+        let v = two_a.montgomery_pow(&EXPONENT);
+        let i = UnpackedScalar::montgomery_mul(&two_a, &v.montgomery_square());
+        let i_minus_one = UnpackedScalar::sub(&i, &constants::R);
+        let r = UnpackedScalar::montgomery_mul(&UnpackedScalar::montgomery_mul(&a, &v), &i_minus_one);
+
+        let r_squared = r.montgomery_square().from_montgomery().pack();
+        let (root, _) = r.from_montgomery().pack().canonical_abs();
+
+        (r_squared.ct_eq(&self.reduce()), root)
+    }
+
+    /// Compute \\( a / b \pmod \ell \\), returning `None` if `b` is zero.
+    ///
+    /// Unlike the `Div` operator, this rejects a zero divisor instead of
+    /// producing a meaningless result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let a = Scalar::from(6u64);
+    /// let b = Scalar::from(3u64);
+    ///
+    /// assert_eq!(a.checked_div(&b), Some(Scalar::from(2u64)));
+    /// assert_eq!(a.checked_div(&Scalar::zero()), None);
+    /// ```
+    pub fn checked_div(&self, rhs: &Scalar) -> Option<Scalar> {
+        if rhs.ct_eq(&Scalar::zero()).into() {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+
+    /// Negate this `Scalar` in place, computing \\( -s \pmod \ell \\).
+    ///
+    /// This is equivalent to `s = -&s`, but avoids constructing a
+    /// temporary `Scalar`.  It runs in constant time, so it is safe to
+    /// use on secret scalars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let mut s = Scalar::from(42u64);
+    /// s.negate();
+    ///
+    /// assert_eq!(s + Scalar::from(42u64), Scalar::zero());
+    /// ```
+    #[allow(non_snake_case)]
+    pub fn negate(&mut self) {
+        // As in the Neg impl, the input may come from Scalar::from_bits,
+        // so reduce it before subtracting from zero.
+        let self_R = UnpackedScalar::mul_internal(&self.unpack(), &constants::R);
+        let self_mod_l = UnpackedScalar::montgomery_reduce(&self_R);
+        self.bytes = UnpackedScalar::sub(&UnpackedScalar::zero(), &self_mod_l).to_bytes();
+    }
+
+    /// Compute \\( s \cdot 2\^k \pmod \ell \\) by repeated doubling.
+    ///
+    /// This runs in time depending only on `k`, not on `self`, so it is
+    /// safe to use on secret scalars with a public shift amount.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from(3u64).mul_pow2(4), Scalar::from(48u64));
+    /// ```
+    pub fn mul_pow2(&self, k: u32) -> Scalar {
+        // Adding zero reduces an input which came from Scalar::from_bits.
+        let mut s = self + Scalar::zero();
+        for _ in 0..k {
+            s = s + s;
+        }
+        s
+    }
+
+    /// Compute \\( s \cdot 2\^{-k} \pmod \ell \\), by multiplying by
+    /// the inverse of two \\(k\\) times.
+    ///
+    /// Like `mul_pow2`, this runs in time depending only on `k`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from(48u64).div_pow2(4), Scalar::from(3u64));
+    /// ```
+    pub fn div_pow2(&self, k: u32) -> Scalar {
+        let mut s = self + Scalar::zero();
+        for _ in 0..k {
+            s *= constants::TWO_INV;
+        }
+        s
+    }
+
+    /// Compute \\( s / 2 \pmod \ell \\), by multiplying by the
+    /// precomputed `constants::TWO_INV`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(7u64);
+    ///
+    /// assert_eq!(s.half() + s.half(), s);
+    /// ```
+    pub fn half(&self) -> Scalar {
+        self * constants::TWO_INV
+    }
+
+    /// Compute \\( k \cdot s \pmod \ell \\) for a small public
+    /// multiplier \\( k \\).
+    ///
+    /// For \\( k < 4 \\), this reduces `self` and uses double-and-add
+    /// on the unpacked scalar.  Reducing costs about half a general
+    /// multiplication and each modular addition a further tenth or so,
+    /// so for larger \\( k \\) double-and-add is slower, and this
+    /// falls back to `self * Scalar::from(k)`.
+    ///
+    /// This runs in time depending only on `k`, not on `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from(5u64).mul_small(3), Scalar::from(15u64));
+    /// ```
+    pub fn mul_small(&self, k: u32) -> Scalar {
+        if k >= 4 {
+            return self * Scalar::from(k);
+        }
+        // UnpackedScalar::add requires reduced inputs.
+        let s = self.reduce().unpack();
+        let mut acc = UnpackedScalar::zero();
+        for i in (0..(32 - k.leading_zeros())).rev() {
+            acc = UnpackedScalar::add(&acc, &acc);
+            if (k >> i) & 1 == 1 {
+                acc = UnpackedScalar::add(&acc, &s);
+            }
+        }
+        acc.pack()
+    }
+
     /// Given a slice of nonzero (possibly secret) `Scalar`s,
     /// compute their inverses in a batch.
     ///
@@ -821,6 +1745,203 @@ impl Scalar {
         ret
     }
 
+    /// Given a slice of nonzero (possibly secret) `Scalar`s,
+    /// compute their inverses in a batch, leaving `inputs` unchanged.
+    ///
+    /// This is the same as `batch_invert`, but writes the inverses to a
+    /// new vector, for when the original values are still needed.
+    ///
+    /// # Return
+    ///
+    /// A vector whose `i`-th entry is the inverse of `inputs[i]`.
+    ///
+    /// # Warning
+    ///
+    /// All input `Scalars` **MUST** be nonzero.  If you cannot
+    /// *prove* that this is the case, you **SHOULD NOT USE THIS
+    /// FUNCTION**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate curve25519_dalek;
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// # fn main() {
+    /// let scalars = [Scalar::from(3u64), Scalar::from(5u64)];
+    ///
+    /// let inverses = Scalar::batch_invert_to(&scalars);
+    ///
+    /// assert_eq!(inverses[0], Scalar::from(3u64).invert());
+    /// assert_eq!(inverses[1], Scalar::from(5u64).invert());
+    /// assert_eq!(scalars[0], Scalar::from(3u64));
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert_to(inputs: &[Scalar]) -> Vec<Scalar> {
+        let mut inverses = inputs.to_vec();
+        Scalar::batch_invert(&mut inverses);
+        inverses
+    }
+
+    /// Compute the inner product \\( \sum\_i a\_i b\_i \\) of two
+    /// slices of scalars.
+    ///
+    /// Each term is a single Montgomery multiplication, giving
+    /// \\( a\_i b\_i / R \\), and the terms are summed unpacked; one
+    /// final multiplication by \\( R\^2 \\) then removes the factor of
+    /// \\( 1/R \\).  This is \\( n + 1 \\) Montgomery multiplications
+    /// for slices of length \\( n \\), rather than the \\( 2n \\) of
+    /// summing `a[i] * b[i]`.  An empty inner product is zero.
+    ///
+    /// # Panics
+    ///
+    /// If `a` and `b` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let a = [Scalar::from(1u64), Scalar::from(2u64)];
+    /// let b = [Scalar::from(3u64), Scalar::from(4u64)];
+    ///
+    /// assert_eq!(Scalar::inner_product(&a, &b), Scalar::from(11u64));
+    /// ```
+    pub fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+        assert_eq!(a.len(), b.len());
+
+        let mut acc = UnpackedScalar::zero();
+        for (a_i, b_i) in a.iter().zip(b.iter()) {
+            let term = UnpackedScalar::montgomery_mul(&a_i.unpack(), &b_i.unpack());
+            acc = UnpackedScalar::add(&acc, &term);
+        }
+        UnpackedScalar::montgomery_mul(&acc, &constants::RR).pack()
+    }
+
+    /// Compute the first `n` powers \\( 1, x, x\^2, \ldots, x\^{n-1} \\)
+    /// of `x`.
+    ///
+    /// Each power is the previous one multiplied by `x`, so this costs
+    /// \\( n - 1 \\) multiplications.  For `n = 0` the vector is empty,
+    /// and for `n = 1` it is just `[1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let x = Scalar::from(3u64);
+    ///
+    /// assert_eq!(
+    ///     Scalar::powers(&x, 3),
+    ///     vec![Scalar::one(), Scalar::from(3u64), Scalar::from(9u64)],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn powers(x: &Scalar, n: usize) -> Vec<Scalar> {
+        let mut powers = Vec::with_capacity(n);
+        let mut current = Scalar::one();
+        for i in 0..n {
+            if i > 0 {
+                current *= x;
+            }
+            powers.push(current);
+        }
+        powers
+    }
+
+    /// Evaluate the polynomial with coefficients `coeffs` at `x`,
+    /// using Horner's rule.
+    ///
+    /// The coefficients are given highest-degree first, so that
+    /// `coeffs = [c_n, ..., c_1, c_0]` evaluates
+    /// \\( c\_n x\^n + \cdots + c\_1 x + c\_0 \\).  An empty slice
+    /// evaluates to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// // 3x^2 + 2x + 1 at x = 2
+    /// let coeffs = [Scalar::from(3u64), Scalar::from(2u64), Scalar::one()];
+    /// let y = Scalar::horner_eval(&coeffs, &Scalar::from(2u64));
+    ///
+    /// assert_eq!(y, Scalar::from(17u64));
+    /// ```
+    #[allow(non_snake_case)]
+    pub fn horner_eval(coeffs: &[Scalar], x: &Scalar) -> Scalar {
+        // Keeping x in Montgomery form lets each step use a single
+        // Montgomery multiplication, with the accumulator left
+        // unpacked between iterations.
+        let x_R = x.unpack().to_montgomery();
+        let mut acc = UnpackedScalar::zero();
+        for c in coeffs {
+            let acc_x = UnpackedScalar::montgomery_mul(&acc, &x_R);
+            acc = UnpackedScalar::add(&acc_x, &c.unpack());
+        }
+        // As in `Add`, the coefficients may not be reduced, so reduce
+        // the result mod l before repacking.
+        let acc_R = UnpackedScalar::mul_internal(&acc, &constants::R);
+        UnpackedScalar::montgomery_reduce(&acc_R).pack()
+    }
+
+    /// Compute the Lagrange coefficient \\( L\_i(0) \\) for the
+    /// interpolation point `x_i`, given the x-coordinates `x_coords`
+    /// of all the points being interpolated.
+    ///
+    /// This is
+    /// \\( L\_i(0) = \prod\_{j \neq i} x\_j / (x\_j - x\_i) \\),
+    /// so that a polynomial \\( f \\) of degree less than
+    /// `x_coords.len()` satisfies
+    /// \\( f(0) = \sum\_i L\_i(0) f(x\_i) \\), as when
+    /// reconstructing a Shamir-shared secret.  The numerator and
+    /// denominator are accumulated separately, so only a single
+    /// inversion is needed.
+    ///
+    /// The x-coordinates are treated as public: `x_i` is located in
+    /// `x_coords` by a variable-time comparison.
+    ///
+    /// # Requirements
+    ///
+    /// The entries of `x_coords` must be distinct and nonzero.  Another
+    /// x-coordinate repeated in `x_coords` makes the denominator zero,
+    /// and the result is then zero rather than a meaningful
+    /// coefficient.
+    ///
+    /// # Panics
+    ///
+    /// If `x_i` does not occur in `x_coords` exactly once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// // Shares of f(x) = 7 + 3x at x = 1 and x = 2.
+    /// let x_coords = [Scalar::from(1u64), Scalar::from(2u64)];
+    /// let shares = [Scalar::from(10u64), Scalar::from(13u64)];
+    ///
+    /// let secret: Scalar = x_coords
+    ///     .iter()
+    ///     .zip(shares.iter())
+    ///     .map(|(x_i, y_i)| Scalar::lagrange_coefficient(x_i, &x_coords) * y_i)
+    ///     .sum();
+    ///
+    /// assert_eq!(secret, Scalar::from(7u64));
+    /// ```
+    pub fn lagrange_coefficient(x_i: &Scalar, x_coords: &[Scalar]) -> Scalar {
+        assert_eq!(x_coords.iter().filter(|x_j| *x_j == x_i).count(), 1);
+
+        let mut numerator = Scalar::one();
+        let mut denominator = Scalar::one();
+        for x_j in x_coords.iter().filter(|x_j| *x_j != x_i) {
+            numerator *= x_j;
+            denominator *= x_j - x_i;
+        }
+        numerator * denominator.invert()
+    }
+
     /// Get the bits of the scalar.
     pub(crate) fn bits(&self) -> [i8; 256] {
         let mut bits = [0i8; 256];
@@ -832,6 +1953,16 @@ impl Scalar {
         bits
     }
 
+    /// Get the bits of the scalar, most-significant first.
+    ///
+    /// This is the reverse of the little-endian order used internally,
+    /// suitable for left-to-right double-and-add formulations.
+    pub fn bits_be(&self) -> [i8; 256] {
+        let mut bits = self.bits();
+        bits.reverse();
+        bits
+    }
+
     /// Compute a width-\\(w\\) "Non-Adjacent Form" of this scalar.
     ///
     /// A width-\\(w\\) NAF of a positive integer \\(k\\) is an expression
@@ -996,6 +2127,48 @@ impl Scalar {
         output
     }
 
+    /// Reconstruct a `Scalar` from signed radix-16 digits, i.e., compute
+    /// $$
+    ///    a = a\_0 + a\_1 16\^1 + \cdots + a_{63} 16\^{63} \pmod \ell.
+    /// $$
+    ///
+    /// This is the inverse of the digit decomposition used for
+    /// fixed-window scalar multiplication, which produces digits with
+    /// \\(-8 \leq a_i < 8\\) for \\(0 \leq i < 63\\) and
+    /// \\(-8 \leq a_{63} \leq 8\\).  Digits outside of that range are
+    /// accepted, and the result is still reduced mod \\( \ell \\).
+    ///
+    /// The digits are processed in constant time, so they may be secret.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let mut digits = [0i8; 64];
+    /// digits[0] = -6;
+    /// digits[1] = 3;
+    ///
+    /// assert_eq!(Scalar::from_radix_16(&digits), Scalar::from(42u64));
+    /// ```
+    pub fn from_radix_16(digits: &[i8; 64]) -> Scalar {
+        let sixteen = Scalar::from(16u8);
+        let mut acc = Scalar::zero();
+
+        // Horner's rule, starting from the most significant digit.
+        for &d in digits.iter().rev() {
+            // Compute |d| and its sign without branching.
+            let dmask = d as i16 >> 7;
+            let dabs = Scalar::from(((d as i16 + dmask) ^ dmask) as u8);
+            let d_is_negative = Choice::from((dmask & 1) as u8);
+
+            let digit = Scalar::conditional_select(&dabs, &-&dabs, d_is_negative);
+            acc = acc * sixteen + digit;
+        }
+
+        acc
+    }
+
     /// Returns a size hint indicating how many entries of the return
     /// value of `to_radix_2w` are nonzero.
     pub(crate) fn to_radix_2w_size_hint(w: usize) -> usize {
@@ -1094,12 +2267,55 @@ impl Scalar {
         digits
     }
 
+    /// Returns an iterator over the signed radix-\\(2\^w\\) digits of this
+    /// `Scalar`, least significant first, for \\(4 \leq w \leq 8\\).
+    ///
+    /// The digits are the same as those of the fixed-size array forms
+    /// (`to_radix_16` when \\(w = 4\\)), with the same recentering into
+    /// \\([-2\^w/2, 2\^w/2)\\), but are computed lazily.  There are
+    /// \\(\lceil 256/w \rceil\\) digits, plus one extra digit when
+    /// \\(w = 8\\) to hold the final carry.
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not in the range \\(4 \leq w \leq 8\\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(0x1fu64);
+    /// let digits: Vec<i8> = s.radix_2w_digits(4).take(3).collect();
+    ///
+    /// // 0x1f = -1 + 2*16
+    /// assert_eq!(digits, vec![-1, 2, 0]);
+    /// ```
+    pub fn radix_2w_digits(&self, w: usize) -> impl Iterator<Item = i8> {
+        assert!((4..=8).contains(&w), "invalid radix parameter");
+
+        use byteorder::{ByteOrder, LittleEndian};
+
+        let mut scalar64x4 = [0u64; 4];
+        LittleEndian::read_u64_into(&self.bytes, &mut scalar64x4[0..4]);
+
+        Radix2wDigits {
+            scalar64x4,
+            w,
+            index: 0,
+            count: Scalar::to_radix_2w_size_hint(w),
+            carry: 0,
+        }
+    }
+
     /// Unpack this `Scalar` to an `UnpackedScalar` for faster arithmetic.
     pub(crate) fn unpack(&self) -> UnpackedScalar {
         UnpackedScalar::from_bytes(&self.bytes)
     }
 
     /// Reduce this `Scalar` modulo \\(\ell\\).
+    ///
+    /// This runs in constant time, so it is safe to use on secret scalars.
     #[allow(non_snake_case)]
     pub fn reduce(&self) -> Scalar {
         let x = self.unpack();
@@ -1108,6 +2324,68 @@ impl Scalar {
         x_mod_l.pack()
     }
 
+    /// Convert this `Scalar` to Montgomery form \\( aR \pmod \ell \\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let a = Scalar::from(6u64);
+    /// let b = Scalar::from(7u64);
+    ///
+    /// let ab = &a.to_montgomery() * &b.to_montgomery();
+    /// assert_eq!(Scalar::from_montgomery(&ab), Scalar::from(42u64));
+    /// ```
+    pub fn to_montgomery(&self) -> MontgomeryScalar {
+        MontgomeryScalar(self.unpack().to_montgomery())
+    }
+
+    /// Convert a `MontgomeryScalar` back to a (reduced) `Scalar`.
+    pub fn from_montgomery(m: &MontgomeryScalar) -> Scalar {
+        m.0.from_montgomery().pack()
+    }
+
+    /// Reduce this `Scalar` modulo \\(\ell\\) by a single conditional
+    /// subtraction of \\(\ell\\), in constant time.
+    ///
+    /// All arithmetic on `Scalar`s is already performed modulo
+    /// \\(\ell\\), so this is only needed to canonicalize a `Scalar`
+    /// constructed directly from bytes, for instance with `from_bits`.
+    ///
+    /// The input must be less than \\(2\ell\\); for arbitrary 255-bit
+    /// inputs, use `reduce` instead.
+    ///
+    /// # Panics
+    ///
+    /// If this `Scalar` is not less than \\(2\ell\\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// // l + 5
+    /// let mut bytes = constants::BASEPOINT_ORDER.to_bytes();
+    /// bytes[0] += 5;
+    /// let s = Scalar::from_bits(bytes);
+    ///
+    /// assert_eq!(s.reduce_once(), Scalar::from(5u64));
+    /// ```
+    pub fn reduce_once(&self) -> Scalar {
+        const TWO_L: Scalar = Scalar {
+            bytes: [
+                0xda, 0xa7, 0xeb, 0xb9, 0x34, 0xc6, 0x24, 0xb0,
+                0xac, 0x39, 0xef, 0x45, 0xbd, 0xf3, 0xbd, 0x29,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+            ],
+        };
+        assert!(self.ct_lt(&TWO_L) == 1, "reduce_once requires an input less than 2l");
+        UnpackedScalar::sub(&self.unpack(), &constants::L).pack()
+    }
+
     /// Check whether this `Scalar` is the canonical representative mod \\(\ell\\).
     ///
     /// This is intended for uses like input validation, where variable-time code is acceptable.
@@ -1129,6 +2407,232 @@ impl Scalar {
     pub fn is_canonical(&self) -> bool {
         *self == self.reduce()
     }
+
+    /// Check in constant time whether `self < other`, comparing the
+    /// integers represented by the two scalars.
+    ///
+    /// Both scalars are assumed to be canonical (reduced mod
+    /// \\( \ell \\)); otherwise the result compares the unreduced
+    /// representatives.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if `self < other`;
+    /// - `0u8` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let five = Scalar::from(5u64);
+    /// let seven = Scalar::from(7u64);
+    ///
+    /// assert_eq!(five.ct_lt(&seven), 1u8);
+    /// assert_eq!(seven.ct_lt(&five), 0u8);
+    /// ```
+    pub fn ct_lt(&self, other: &Scalar) -> u8 {
+        // Compute self - other byte by byte, and return the final borrow.
+        let mut borrow = 0i16;
+        for i in 0..32 {
+            let diff = (self.bytes[i] as i16) - (other.bytes[i] as i16) - borrow;
+            borrow = (diff >> 8) & 1;
+        }
+        borrow as u8
+    }
+
+    /// Check in constant time whether `self > other`, comparing the
+    /// integers represented by the two scalars.
+    ///
+    /// As for `ct_lt`, both scalars are assumed to be canonical.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if `self > other`;
+    /// - `0u8` otherwise.
+    pub fn ct_gt(&self, other: &Scalar) -> u8 {
+        other.ct_lt(self)
+    }
+
+    /// Normalize this `Scalar` to the smaller of its two
+    /// representatives \\( \{ s, \ell - s \} \\), in constant time.
+    ///
+    /// The scalar is first reduced mod \\( \ell \\).  If the result is
+    /// greater than `Scalar::half_order()`, it is negated.
+    ///
+    /// # Return
+    ///
+    /// A pair `(t, negated)`, where `t` is at most
+    /// \\( (\ell - 1) / 2 \\), and `negated` is `1u8` if `t` is
+    /// \\( \ell - s \\), or `0u8` if `t` is \\( s \\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = -Scalar::from(5u64);
+    ///
+    /// assert_eq!(s.canonical_abs(), (Scalar::from(5u64), 1u8));
+    /// assert_eq!(Scalar::from(5u64).canonical_abs(), (Scalar::from(5u64), 0u8));
+    /// ```
+    pub fn canonical_abs(&self) -> (Scalar, u8) {
+        let mut t = self.reduce();
+        let negated = t.ct_gt(&Scalar::half_order());
+        t.conditional_negate(Choice::from(negated));
+        (t, negated)
+    }
+
+    /// Compute `a + b` (mod \\( \ell \\)), reporting whether the sum
+    /// wrapped around \\( \ell \\).
+    ///
+    /// Both inputs are reduced mod \\( \ell \\) first, so the flag
+    /// describes the sum of their canonical representatives.
+    ///
+    /// # Return
+    ///
+    /// A pair `(a + b, carry)`, where `carry` is `1u8` if
+    /// \\( a + b \geq \ell \\), and `0u8` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let l_minus_1 = Scalar::order_minus_one();
+    ///
+    /// assert_eq!(Scalar::add_with_carry(&l_minus_1, &Scalar::one()), (Scalar::zero(), 1u8));
+    /// ```
+    pub fn add_with_carry(a: &Scalar, b: &Scalar) -> (Scalar, u8) {
+        let (sum, carry) = UnpackedScalar::add_with_carry(&a.reduce().unpack(), &b.reduce().unpack());
+        (sum.pack(), carry)
+    }
+
+    /// Compute `a - b` (mod \\( \ell \\)), reporting whether the
+    /// difference wrapped around zero.
+    ///
+    /// As for `add_with_carry`, both inputs are reduced first.
+    ///
+    /// # Return
+    ///
+    /// A pair `(a - b, borrow)`, where `borrow` is `1u8` if
+    /// \\( a < b \\), and `0u8` otherwise.
+    pub fn sub_with_borrow(a: &Scalar, b: &Scalar) -> (Scalar, u8) {
+        let (difference, borrow) = UnpackedScalar::sub_with_borrow(&a.reduce().unpack(), &b.reduce().unpack());
+        (difference.pack(), borrow)
+    }
+
+    /// Check in constant time whether this `Scalar` is zero.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if `self` is zero;
+    /// - `0u8` otherwise.
+    pub fn is_zero(&self) -> u8 {
+        self.ct_eq(&Scalar::ZERO).unwrap_u8()
+    }
+
+    /// Check in constant time whether this `Scalar` is one.
+    ///
+    /// # Return
+    ///
+    /// - `1u8` if `self` is one;
+    /// - `0u8` otherwise.
+    pub fn is_one(&self) -> u8 {
+        self.ct_eq(&Scalar::ONE).unwrap_u8()
+    }
+
+    /// Compare each element of `haystack` with `needle` in constant
+    /// time.
+    ///
+    /// Every element is compared with `ct_eq`, so the time taken
+    /// depends only on `haystack.len()`.  As for `ct_eq`, the byte
+    /// encodings are compared, so an unreduced scalar does not match
+    /// its reduction.
+    ///
+    /// # Return
+    ///
+    /// A vector with one entry per element of `haystack`, which is
+    /// `1u8` where that element equals `needle`, and `0u8` elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let haystack = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(1u64)];
+    ///
+    /// assert_eq!(Scalar::ct_eq_broadcast(&haystack, &Scalar::from(1u64)), vec![1u8, 0u8, 1u8]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn ct_eq_broadcast(haystack: &[Scalar], needle: &Scalar) -> Vec<u8> {
+        haystack.iter().map(|s| s.ct_eq(needle).unwrap_u8()).collect()
+    }
+}
+
+/// Iterator over the signed radix-\\(2\^w\\) digits of a `Scalar`,
+/// returned by `Scalar::radix_2w_digits`.
+///
+/// This computes the same digits as `Scalar::to_radix_2w`, one at a
+/// time, keeping only the carry between windows.
+struct Radix2wDigits {
+    scalar64x4: [u64; 4],
+    w: usize,
+    index: usize,
+    count: usize,
+    carry: u64,
+}
+
+impl Iterator for Radix2wDigits {
+    type Item = i8;
+
+    fn next(&mut self) -> Option<i8> {
+        if self.index >= self.count {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+
+        let w = self.w;
+        // When w = 8, the final carry is emitted as an extra digit; see
+        // the comment in to_radix_2w.
+        let digits_count = if w == 8 { self.count - 1 } else { self.count };
+
+        if i == digits_count {
+            return Some(self.carry as i8);
+        }
+
+        let radix: u64 = 1 << w;
+        let window_mask: u64 = radix - 1;
+
+        // Construct a buffer of bits of the scalar, starting at `bit_offset`.
+        let bit_offset = i*w;
+        let u64_idx = bit_offset / 64;
+        let bit_idx = bit_offset % 64;
+
+        let bit_buf = if bit_idx < 64 - w || u64_idx == 3 {
+            self.scalar64x4[u64_idx] >> bit_idx
+        } else {
+            (self.scalar64x4[u64_idx] >> bit_idx) | (self.scalar64x4[1+u64_idx] << (64 - bit_idx))
+        };
+
+        // Recenter coefficients from [0,2^w) to [-2^w/2, 2^w/2)
+        let coef = self.carry + (bit_buf & window_mask);
+        self.carry = (coef + radix/2) >> w;
+        let mut digit = ((coef as i64) - (self.carry << w) as i64) as i8;
+
+        // When w < 8, fold the final carry onto the last digit.
+        if w < 8 && i == digits_count - 1 {
+            digit += (self.carry << w) as i8;
+        }
+
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
 }
 
 impl UnpackedScalar {
@@ -1154,42 +2658,50 @@ impl UnpackedScalar {
         // _10000
         let mut y = UnpackedScalar::montgomery_mul(&_1111, &_1);
 
-        #[inline]
-        fn square_multiply(y: &mut UnpackedScalar, squarings: usize, x: &UnpackedScalar) {
-            for _ in 0..squarings {
-                *y = y.montgomery_square();
-            }
-            *y = UnpackedScalar::montgomery_mul(y, x);
-        }
-
-        square_multiply(&mut y, 123 + 3, &_101);
-        square_multiply(&mut y,   2 + 2, &_11);
-        square_multiply(&mut y,   1 + 4, &_1111);
-        square_multiply(&mut y,   1 + 4, &_1111);
-        square_multiply(&mut y,       4, &_1001);
-        square_multiply(&mut y,       2, &_11);
-        square_multiply(&mut y,   1 + 4, &_1111);
-        square_multiply(&mut y,   1 + 3, &_101);
-        square_multiply(&mut y,   3 + 3, &_101);
-        square_multiply(&mut y,       3, &_111);
-        square_multiply(&mut y,   1 + 4, &_1111);
-        square_multiply(&mut y,   2 + 3, &_111);
-        square_multiply(&mut y,   2 + 2, &_11);
-        square_multiply(&mut y,   1 + 4, &_1011);
-        square_multiply(&mut y,   2 + 4, &_1011);
-        square_multiply(&mut y,   6 + 4, &_1001);
-        square_multiply(&mut y,   2 + 2, &_11);
-        square_multiply(&mut y,   3 + 2, &_11);
-        square_multiply(&mut y,   3 + 2, &_11);
-        square_multiply(&mut y,   1 + 4, &_1001);
-        square_multiply(&mut y,   1 + 3, &_111);
-        square_multiply(&mut y,   2 + 4, &_1111);
-        square_multiply(&mut y,   1 + 4, &_1011);
-        square_multiply(&mut y,       3, &_101);
-        square_multiply(&mut y,   2 + 4, &_1111);
-        square_multiply(&mut y,       3, &_101);
-        square_multiply(&mut y,   1 + 2, &_11);
+        y.square_multiply(123 + 3, &_101);
+        y.square_multiply(  2 + 2, &_11);
+        y.square_multiply(  1 + 4, &_1111);
+        y.square_multiply(  1 + 4, &_1111);
+        y.square_multiply(      4, &_1001);
+        y.square_multiply(      2, &_11);
+        y.square_multiply(  1 + 4, &_1111);
+        y.square_multiply(  1 + 3, &_101);
+        y.square_multiply(  3 + 3, &_101);
+        y.square_multiply(      3, &_111);
+        y.square_multiply(  1 + 4, &_1111);
+        y.square_multiply(  2 + 3, &_111);
+        y.square_multiply(  2 + 2, &_11);
+        y.square_multiply(  1 + 4, &_1011);
+        y.square_multiply(  2 + 4, &_1011);
+        y.square_multiply(  6 + 4, &_1001);
+        y.square_multiply(  2 + 2, &_11);
+        y.square_multiply(  3 + 2, &_11);
+        y.square_multiply(  3 + 2, &_11);
+        y.square_multiply(  1 + 4, &_1001);
+        y.square_multiply(  1 + 3, &_111);
+        y.square_multiply(  2 + 4, &_1111);
+        y.square_multiply(  1 + 4, &_1011);
+        y.square_multiply(      3, &_101);
+        y.square_multiply(  2 + 4, &_1111);
+        y.square_multiply(      3, &_101);
+        y.square_multiply(  1 + 2, &_11);
+
+        y
+    }
 
+    /// Raises an UnpackedScalar in Montgomery form to the power
+    /// `exponent`, given as a little-endian integer.
+    ///
+    /// The exponent is public, so this branches on its bits.
+    fn montgomery_pow(&self, exponent: &[u8; 32]) -> UnpackedScalar {
+        // R is the Montgomery form of one.
+        let mut y = constants::R;
+        for i in (0..256).rev() {
+            y = y.montgomery_square();
+            if (exponent[i >> 3] >> (i & 7)) & 1 == 1 {
+                y = UnpackedScalar::montgomery_mul(&y, self);
+            }
+        }
         y
     }
 
@@ -1435,34 +2947,235 @@ mod test {
         //
         // This test is adapted from the one suggested by Quarkslab.
 
-        let large_bytes = [
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
-        ];
+        let large_bytes = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+        ];
+
+        let a = Scalar::from_bytes_mod_order(large_bytes);
+        let b = Scalar::from_bits(large_bytes);
+
+        assert_eq!(a, b.reduce());
+
+        let a_3 = a + a + a;
+        let b_3 = b + b + b;
+
+        assert_eq!(a_3, b_3);
+
+        let neg_a = -a;
+        let neg_b = -b;
+
+        assert_eq!(neg_a, neg_b);
+
+        let minus_a_3 = Scalar::zero() - a - a - a;
+        let minus_b_3 = Scalar::zero() - b - b - b;
+
+        assert_eq!(minus_a_3, minus_b_3);
+        assert_eq!(minus_a_3, -a_3);
+        assert_eq!(minus_b_3, -b_3);
+    }
+
+    #[test]
+    fn div_is_mul_by_inverse() {
+        let prod = &X * &Y;
+        assert_eq!(&prod / &Y, X);
+        assert_eq!(prod / Y, X);
+        assert_eq!(X.checked_div(&Y), Some(&X * &Y.invert()));
+        assert_eq!(X.checked_div(&Scalar::zero()), None);
+    }
+
+    #[test]
+    fn ops_with_u64() {
+        assert_eq!(&X * 3u64, &X * &Scalar::from(3u64));
+        assert_eq!(X * 3u64, X + X + X);
+        assert_eq!(&X + 5u64, &X + &Scalar::from(5u64));
+        assert_eq!(Scalar::ZERO + u64::max_value(), Scalar::from(u64::max_value()));
+    }
+
+    #[test]
+    fn order_boundary_scalars() {
+        let l_minus_1 = Scalar::order_minus_one();
+        assert!(l_minus_1.is_canonical());
+        assert_eq!(&l_minus_1 + &Scalar::one(), Scalar::zero());
+        assert_eq!(l_minus_1, -Scalar::one());
+
+        let half = Scalar::half_order();
+        assert!(half.is_canonical());
+        assert_eq!(half + half, l_minus_1);
+
+        // l + 1 is not canonical, but reduces to 1
+        let mut l_plus_1 = constants::BASEPOINT_ORDER.to_bytes();
+        l_plus_1[0] += 1;
+        assert_eq!(Scalar::from_canonical_bytes(l_plus_1), None);
+        assert_eq!(Scalar::from_bytes_mod_order(l_plus_1), Scalar::one());
+    }
+
+    #[cfg(feature = "debug-internals")]
+    #[test]
+    fn from_hash_debug() {
+        use sha2::{Digest, Sha512};
+
+        let msg = b"To really appreciate architecture, you may even need to commit a murder";
+        let (s, digest) = Scalar::from_hash_debug(Sha512::new().chain(&msg[..]));
+
+        assert_eq!(&digest[..], &Sha512::digest(msg)[..]);
+        assert_eq!(s, Scalar::from_bytes_mod_order_wide(&digest));
+        assert_eq!(s, Scalar::from_hash(Sha512::new().chain(&msg[..])));
+    }
+
+    #[test]
+    fn reduce_once() {
+        // l + 5
+        let mut bytes = constants::BASEPOINT_ORDER.to_bytes();
+        bytes[0] += 5;
+        let s = Scalar::from_bits(bytes);
+        assert!(!s.is_canonical());
+        assert_eq!(s.reduce_once(), Scalar::from(5u64));
+
+        // Canonical inputs are unchanged
+        assert_eq!(X.reduce_once(), X);
+        assert_eq!(Scalar::zero().reduce_once(), Scalar::zero());
+        assert_eq!(Scalar::order_minus_one().reduce_once(), Scalar::order_minus_one());
+
+        // Inputs in [2^253, 2l) are valid too
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0x20;
+        let two_253 = Scalar::from_bits(bytes);
+        assert_eq!(two_253.reduce_once(), two_253.reduce());
+
+        // (l - 1) + l = 2l - 1
+        let mut bytes = constants::BASEPOINT_ORDER_MINUS_1.to_bytes();
+        let mut carry = 0u16;
+        for (byte, l_byte) in bytes.iter_mut().zip(constants::L_BYTES.iter()) {
+            carry += *byte as u16 + *l_byte as u16;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        assert_eq!(Scalar::from_bits(bytes).reduce_once(), Scalar::order_minus_one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn reduce_once_rejects_two_l() {
+        let mut bytes = [0u8; 32];
+        let mut carry = 0u16;
+        for (byte, l_byte) in bytes.iter_mut().zip(constants::L_BYTES.iter()) {
+            carry += 2 * *l_byte as u16;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        Scalar::from_bits(bytes).reduce_once();
+    }
+
+    #[test]
+    #[should_panic]
+    fn reduce_once_rejects_largest_from_bits() {
+        // 2^255 - 1, the largest value from_bits accepts
+        Scalar::from_bits([0xff; 32]).reduce_once();
+    }
+
+    #[test]
+    fn u64_limbs_roundtrip() {
+        let limbs = [0x8408475d34b45a4e, 0x527dc21b64b41359, 0x4442cc1b1085a552, 0x04f2d979a8f449d4];
+        assert_eq!(Scalar::from_u64_limbs(limbs), X);
+        assert_eq!(X.to_u64_limbs(), limbs);
+
+        // l reduces to zero.
+        let l = [0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0, 0x1000000000000000];
+        assert_eq!(Scalar::from_u64_limbs(l), Scalar::zero());
+        assert_eq!(constants::BASEPOINT_ORDER.to_u64_limbs(), l);
+    }
+
+    #[test]
+    fn from_hex_be() {
+        let x = "4f2d979a8f449d44442cc1b1085a552527dc21b64b413598408475d34b45a4e";
+        assert_eq!(Scalar::from_hex_be(x), Some(X));
+        assert_eq!(Scalar::from_hex_be(&["0", x].concat()), Some(X));
 
-        let a = Scalar::from_bytes_mod_order(large_bytes);
-        let b = Scalar::from_bits(large_bytes);
+        // l reduces to zero.
+        let l = "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+        assert_eq!(Scalar::from_hex_be(l), Some(Scalar::zero()));
 
-        assert_eq!(a, b.reduce());
+        assert_eq!(Scalar::from_hex_be(""), None);
+        assert_eq!(Scalar::from_hex_be("xyz"), None);
+        assert_eq!(Scalar::from_hex_be(&["00", x].concat()), None);
+    }
 
-        let a_3 = a + a + a;
-        let b_3 = b + b + b;
+    #[test]
+    fn display_as_big_endian_hex() {
+        assert_eq!(
+            format!("{}", Scalar::one()),
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert_eq!(
+            format!("{}", X),
+            "04f2d979a8f449d44442cc1b1085a552527dc21b64b413598408475d34b45a4e"
+        );
+        // Unreduced scalars are displayed by their canonical value.
+        let l = Scalar::from_bits(constants::BASEPOINT_ORDER.bytes);
+        assert_eq!(format!("{}", l), format!("{}", Scalar::zero()));
+    }
 
-        assert_eq!(a_3, b_3);
+    #[test]
+    fn hex_roundtrip() {
+        assert_eq!(Scalar::from_hex(&X.to_hex()), Some(X));
+        assert_eq!(Scalar::from_hex(&Scalar::zero().to_hex()), Some(Scalar::zero()));
 
-        let neg_a = -a;
-        let neg_b = -b;
+        // l is non-canonical
+        assert_eq!(Scalar::from_hex(&constants::BASEPOINT_ORDER.to_hex()), None);
+        assert_eq!(Scalar::from_hex("00"), None);
 
-        assert_eq!(neg_a, neg_b);
+        // The hex encoding is big-endian, as for Display and from_hex_be.
+        assert_eq!(X.to_hex(), format!("{}", X));
+        assert_eq!(Scalar::from_hex(&format!("{}", X)), Some(X));
+        assert_eq!(Scalar::from_hex_be(&X.to_hex()), Some(X));
+    }
 
-        let minus_a_3 = Scalar::zero() - a - a - a;
-        let minus_b_3 = Scalar::zero() - b - b - b;
+    #[test]
+    fn from_and_into_byte_array() {
+        let s = Scalar::from([255u8; 32]);
+        assert!(s.is_canonical());
+        assert_eq!(s, Scalar::from_bytes_mod_order([255u8; 32]));
 
-        assert_eq!(minus_a_3, minus_b_3);
-        assert_eq!(minus_a_3, -a_3);
-        assert_eq!(minus_b_3, -b_3);
+        let bytes: [u8; 32] = X.into();
+        assert_eq!(bytes, X.to_bytes());
+
+        // Unreduced scalars are reduced on conversion to bytes
+        let unreduced = Scalar::from_bits([255u8; 32]);
+        let bytes: [u8; 32] = unreduced.into();
+        assert_eq!(bytes, unreduced.reduce().to_bytes());
+        assert_ne!(bytes, unreduced.to_bytes());
+    }
+
+    #[test]
+    fn scalar_lookup_table() {
+        let table = ScalarLookupTable::from(&X);
+
+        assert_eq!(table.select(-3), -&(X * Scalar::from(3u64)));
+        assert_eq!(table.select(0), Scalar::zero());
+        for x in -8i8..=8 {
+            let abs = Scalar::from((x as i64).abs() as u64);
+            let expected = if x < 0 { -(X * abs) } else { X * abs };
+            assert_eq!(table.select(x), expected);
+        }
+    }
+
+    #[test]
+    fn encoded_size() {
+        let buf = [0u8; Scalar::ENCODED_SIZE];
+        assert_eq!(buf.len(), X.as_bytes().len());
+    }
+
+    #[test]
+    fn zero_and_one_constants() {
+        const ZEROS: [Scalar; 4] = [Scalar::ZERO; 4];
+        for z in ZEROS.iter() {
+            assert_eq!(*z, Scalar::zero());
+        }
+        assert_eq!(Scalar::ONE, Scalar::one());
+        assert_eq!(Scalar::ONE + Scalar::ZERO, Scalar::from(1u64));
     }
 
     #[test]
@@ -1581,6 +3294,68 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_bytes_mod_order_wide_slice() {
+        let mut bignum = [0u8; 64];
+        for i in 0..32 {
+            bignum[   i] = X[i];
+            bignum[32+i] = X[i];
+        }
+
+        // A full 64-byte slice matches the array constructor
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_slice(&bignum[..]),
+            Some(Scalar::from_bytes_mod_order_wide(&bignum))
+        );
+
+        // A 32-byte slice is zero-extended
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_slice(&bignum[..32]),
+            Some(X)
+        );
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide_slice(&[0xff; 32]),
+            Some(CANONICAL_2_256_MINUS_1)
+        );
+
+        // Over-length slices are rejected
+        assert!(Scalar::from_bytes_mod_order_wide_slice(&[0u8; 65]).is_none());
+    }
+
+    #[test]
+    fn reduce_wide() {
+        // sage: l = 2^252 + 27742317777372353535851937790883648493
+        // sage: x = sum((i+1) * 2^(8*i) for i in range(96))
+        // sage: repr((x % l).digits(256))
+        let bytes: Vec<u8> = (1..97).collect();
+        let expected = Scalar{
+            bytes: [
+                 37,  71, 182,  70,  84, 202, 192,  74,
+                204,  86, 188,  27, 202, 145,  92, 197,
+                147, 182, 144,  47, 185,  63, 214, 248,
+                169, 209,  60,   6, 190, 209, 186,  10,
+            ],
+        };
+        assert_eq!(Scalar::reduce_wide(&bytes), expected);
+
+        // sage: repr(((2^768 - 1) % l).digits(256))
+        let expected = Scalar{
+            bytes: [
+                218, 162, 131, 123, 104,  73, 158,  42,
+                236, 243, 247, 174, 230,  36, 131,  39,
+                101,  91, 236,   4, 108, 220, 101, 128,
+                199, 206, 153,  53, 119,  11,  83,  14,
+            ],
+        };
+        assert_eq!(Scalar::reduce_wide(&[0xff; 96]), expected);
+
+        // Inputs of at most 64 bytes agree with the fixed-size constructors
+        assert_eq!(Scalar::reduce_wide(&[0xff; 32]), CANONICAL_2_256_MINUS_1);
+        assert_eq!(Scalar::reduce_wide(&bytes[..64]),
+                   Scalar::from_bytes_mod_order_wide_slice(&bytes[..64]).unwrap());
+        assert_eq!(Scalar::reduce_wide(&[]), Scalar::zero());
+    }
+
     #[allow(non_snake_case)]
     #[test]
     fn invert() {
@@ -1600,6 +3375,83 @@ mod test {
         assert_eq!(should_be_X, X);
     }
 
+    #[test]
+    fn mul_and_div_pow2() {
+        let s = X;
+        let mut eight_s = Scalar::zero();
+        for _ in 0..8 {
+            eight_s += s;
+        }
+        assert_eq!(s.mul_pow2(3), eight_s);
+        assert_eq!(s.mul_pow2(3).div_pow2(3), s);
+        assert_eq!(s.mul_pow2(0), s);
+        assert_eq!(s.mul_pow2(300).div_pow2(300), s);
+
+        // Halving 1 gives (l+1)/2 = (l-1)/2 + 1
+        assert_eq!(Scalar::one().div_pow2(1), Scalar::half_order() + Scalar::one());
+    }
+
+    #[test]
+    fn half() {
+        let mut rng = rand::thread_rng();
+        for s in &[X, Y, Scalar::one(), Scalar::zero(), Scalar::random(&mut rng)] {
+            assert_eq!(&(s.half()) + &(s.half()), *s);
+            assert_eq!(s.half(), s.div_pow2(1));
+        }
+    }
+
+    #[test]
+    fn sqrt() {
+        let mut rng = rand::thread_rng();
+        for s in &[X, Y, XINV, Scalar::one(), Scalar::order_minus_one(), Scalar::random(&mut rng)] {
+            let square = s * s;
+            let root = square.sqrt().unwrap();
+            assert_eq!(&root * &root, square);
+            assert!(root == *s || root == -s);
+            assert_eq!(root.canonical_abs().1, 0u8);
+        }
+        assert_eq!(Scalar::zero().sqrt(), Some(Scalar::zero()));
+
+        // 2 is not a square mod l, since l = 5 (mod 8), and neither is
+        // 2 times any nonzero square.
+        let two = Scalar::from(2u64);
+        assert_eq!(two.sqrt(), None);
+        assert_eq!((two * X * X).sqrt(), None);
+
+        // -1 is a square, since l = 1 (mod 4).
+        let i = Scalar::order_minus_one().sqrt().unwrap();
+        assert_eq!(i * i, -Scalar::one());
+    }
+
+    #[test]
+    fn mul_small() {
+        assert_eq!(X.mul_small(8), &X * &Scalar::from(8u64));
+
+        // Unreduced inputs are reduced first.
+        let unreduced = Scalar::from_bits([0xff; 32]);
+        for k in [0u32, 1, 2, 3, 8, 255, 256, 1000, 0xffff_ffff].iter() {
+            let k_s = Scalar::from(*k);
+            assert_eq!(X.mul_small(*k), &X * &k_s);
+            assert_eq!(unreduced.mul_small(*k), &unreduced * &k_s);
+        }
+    }
+
+    #[test]
+    fn negate_twice_is_identity() {
+        let mut s = X;
+        s.negate();
+        assert_eq!(s, -&X);
+        s.negate();
+        assert_eq!(s, X);
+
+        // Unreduced inputs are reduced before negation.
+        let mut big = Scalar::from_bits([0xff; 32]);
+        big.negate();
+        assert_eq!(big, -Scalar::from_bits([0xff; 32]));
+        big.negate();
+        assert_eq!(big, Scalar::from_bits([0xff; 32]).reduce());
+    }
+
     #[test]
     fn to_bytes_from_bytes_roundtrips() {
         let unpacked = X.unpack();
@@ -1644,6 +3496,25 @@ mod test {
         assert_eq!(montgomery_reduced.0, expected.unpack().0)
     }
 
+    #[test]
+    fn big_endian_bytes() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let s = Scalar::random(&mut rng);
+            let be = s.to_bytes_be();
+            assert_eq!(Scalar::from_bytes_be(&be), Some(s));
+
+            let mut le = be;
+            le.reverse();
+            assert_eq!(Scalar::from_bytes_be(&be), Scalar::from_canonical_bytes(le));
+        }
+
+        // l itself is non-canonical in either byte order
+        let mut l_be = constants::BASEPOINT_ORDER.to_bytes();
+        l_be.reverse();
+        assert_eq!(Scalar::from_bytes_be(&l_be), None);
+    }
+
     #[test]
     fn canonical_decoding() {
         // canonical encoding of 1667457891
@@ -1663,6 +3534,19 @@ mod test {
         assert!( Scalar::from_canonical_bytes(non_canonical_bytes_because_highbit).is_none() );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_bincode_distinguishes_errors() {
+        use bincode;
+
+        let short_err = bincode::deserialize::<Scalar>(&[0u8; 31]).unwrap_err();
+        let noncanonical_err =
+            bincode::deserialize::<Scalar>(constants::BASEPOINT_ORDER.as_bytes()).unwrap_err();
+
+        assert_ne!(format!("{}", short_err), format!("{}", noncanonical_err));
+        assert!(format!("{}", noncanonical_err).contains("canonically encoded scalar"));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_bincode_scalar_roundtrip() {
@@ -1712,6 +3596,339 @@ mod test {
         }
     }
 
+    #[test]
+    fn square_multiply_reproduces_invert() {
+        // Compute X^(l-2) by left-to-right square-and-multiply.
+        let mut exponent = constants::BASEPOINT_ORDER.bytes;
+        exponent[0] -= 2;
+
+        let x = X.unpack().to_montgomery();
+        let mut y = x;
+        for i in (0..252).rev() {
+            if (exponent[i >> 3] >> (i & 7)) & 1 == 1 {
+                y.square_multiply(1, &x);
+            } else {
+                y = y.montgomery_square();
+            }
+        }
+
+        assert_eq!(y.from_montgomery().pack(), X.invert());
+    }
+
+    #[test]
+    fn ct_lt_and_ct_gt() {
+        let five = Scalar::from(5u64);
+        let seven = Scalar::from(7u64);
+
+        assert_eq!(five.ct_lt(&seven), 1u8);
+        assert_eq!(seven.ct_lt(&five), 0u8);
+        assert_eq!(five.ct_gt(&seven), 0u8);
+        assert_eq!(seven.ct_gt(&five), 1u8);
+
+        assert_eq!(five.ct_lt(&five), 0u8);
+        assert_eq!(five.ct_gt(&five), 0u8);
+
+        let l_minus_1 = Scalar::order_minus_one();
+        assert_eq!(Scalar::zero().ct_lt(&l_minus_1), 1u8);
+        assert_eq!(l_minus_1.ct_gt(&X), 1u8);
+    }
+
+    #[test]
+    fn is_zero_and_is_one() {
+        let mut rng = rand::thread_rng();
+        let r = Scalar::random(&mut rng);
+
+        assert_eq!(Scalar::zero().is_zero(), 1u8);
+        assert_eq!(Scalar::one().is_zero(), 0u8);
+        assert_eq!(r.is_zero(), 0u8);
+
+        assert_eq!(Scalar::zero().is_one(), 0u8);
+        assert_eq!(Scalar::one().is_one(), 1u8);
+        assert_eq!(r.is_one(), 0u8);
+    }
+
+    #[test]
+    fn ct_eq_broadcast() {
+        let mut rng = rand::thread_rng();
+        let mut haystack: Vec<Scalar> = (0..16).map(|_| Scalar::random(&mut rng)).collect();
+        haystack[11] = X;
+
+        let mask = Scalar::ct_eq_broadcast(&haystack, &X);
+        assert_eq!(mask.len(), haystack.len());
+        assert_eq!(mask.iter().position(|&m| m == 1u8), Some(11));
+        assert_eq!(mask.iter().map(|&m| m as usize).sum::<usize>(), 1);
+
+        assert!(Scalar::ct_eq_broadcast(&haystack, &Y).iter().all(|&m| m == 0u8));
+        assert!(Scalar::ct_eq_broadcast(&[], &X).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn sample_through_distribution() {
+        use rand::Rng;
+        use rand::distributions::Standard;
+
+        let mut rng = rand::thread_rng();
+        let scalars: Vec<Scalar> = (&mut rng).sample_iter(Standard).take(10).collect();
+        assert_eq!(scalars.len(), 10);
+        assert_ne!(scalars[0], scalars[1]);
+
+        let s: Scalar = rng.sample(Standard);
+        assert!(s.is_canonical());
+    }
+
+    #[test]
+    fn derive_nonce() {
+        use sha2::Sha512;
+
+        let r_1 = Scalar::derive_nonce::<Sha512>(&X, b"message");
+        let r_2 = Scalar::derive_nonce::<Sha512>(&X, b"message");
+        let r_3 = Scalar::derive_nonce::<Sha512>(&X, b"other message");
+        let r_4 = Scalar::derive_nonce::<Sha512>(&Y, b"message");
+
+        assert_eq!(r_1, r_2);
+        assert_ne!(r_1, r_3);
+        assert_ne!(r_1, r_4);
+
+        // This is H(secret || message), reduced mod l.
+        let mut hash = Sha512::default();
+        hash.update(X.as_bytes());
+        hash.update(b"message");
+        assert_eq!(r_1, Scalar::from_hash(hash));
+    }
+
+    #[test]
+    fn prf_stream() {
+        use sha2::Sha512;
+
+        let stream = Scalar::prf_stream::<Sha512>(&X, 8);
+        assert_eq!(stream.len(), 8);
+        assert_eq!(stream, Scalar::prf_stream::<Sha512>(&X, 8));
+        assert_eq!(stream[..3], Scalar::prf_stream::<Sha512>(&X, 3)[..]);
+        assert_ne!(stream, Scalar::prf_stream::<Sha512>(&Y, 8));
+        for pair in stream.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+
+        // The first output is H(seed || 0u64), reduced mod l.
+        let mut hash = Sha512::default();
+        hash.update(X.as_bytes());
+        hash.update(0u64.to_le_bytes());
+        assert_eq!(stream[0], Scalar::from_hash(hash));
+
+        assert!(Scalar::prf_stream::<Sha512>(&X, 0).is_empty());
+    }
+
+    #[test]
+    fn absorb_into_canonicalizes() {
+        use sha2::{Digest, Sha512};
+
+        let mut h_1 = Sha512::new();
+        let mut h_2 = Sha512::new();
+        X.absorb_into(&mut h_1);
+        // The unreduced representative X + l absorbs the same way.
+        let mut unreduced = Scalar::zero();
+        let mut carry = 0u16;
+        for i in 0..32 {
+            carry += X.bytes[i] as u16 + constants::L_BYTES[i] as u16;
+            unreduced.bytes[i] = carry as u8;
+            carry >>= 8;
+        }
+        assert!(!unreduced.is_canonical());
+        unreduced.absorb_into(&mut h_2);
+        assert_eq!(h_1.clone().finalize(), h_2.finalize());
+
+        let mut expected = Sha512::new();
+        expected.update(b"S");
+        expected.update(32u64.to_le_bytes());
+        expected.update(X.as_bytes());
+        assert_eq!(h_1.finalize(), expected.finalize());
+    }
+
+    #[test]
+    fn canonical_abs() {
+        let half = Scalar::half_order();
+        let l_minus_1 = Scalar::order_minus_one();
+
+        // s > l/2 gives l - s.
+        assert_eq!(l_minus_1.canonical_abs(), (Scalar::one(), 1u8));
+        assert_eq!((half + Scalar::one()).canonical_abs(), (half, 1u8));
+        assert_eq!((-X).canonical_abs(), (X, 1u8));
+
+        // s <= l/2 is unchanged.
+        assert_eq!(half.canonical_abs(), (half, 0u8));
+        assert_eq!(Scalar::zero().canonical_abs(), (Scalar::zero(), 0u8));
+        assert_eq!(Scalar::one().canonical_abs(), (Scalar::one(), 0u8));
+        assert_eq!(X.canonical_abs(), (X, 0u8));
+    }
+
+    #[test]
+    fn add_with_carry_and_sub_with_borrow() {
+        let l_minus_1 = Scalar::order_minus_one();
+
+        assert_eq!(Scalar::add_with_carry(&l_minus_1, &Scalar::one()), (Scalar::zero(), 1u8));
+        assert_eq!(Scalar::add_with_carry(&l_minus_1, &Scalar::zero()), (l_minus_1, 0u8));
+        assert_eq!(Scalar::add_with_carry(&X, &Y), (X + Y, X.ct_gt(&(X + Y))));
+
+        assert_eq!(Scalar::sub_with_borrow(&Scalar::zero(), &Scalar::one()), (l_minus_1, 1u8));
+        assert_eq!(Scalar::sub_with_borrow(&Scalar::one(), &Scalar::one()), (Scalar::zero(), 0u8));
+        assert_eq!(Scalar::sub_with_borrow(&X, &Y), (X - Y, X.ct_lt(&Y)));
+    }
+
+    #[test]
+    fn neg_owned_and_sub() {
+        assert_eq!(-Scalar::one(), Scalar::order_minus_one());
+        assert_eq!(-X, -&X);
+        assert_eq!(-(-X), X);
+
+        assert_eq!(Scalar::zero() - Scalar::one(), Scalar::order_minus_one());
+        assert_eq!(&X - &Y, &X + &(-&Y));
+    }
+
+    #[test]
+    fn hash_from_bytes_256() {
+        use sha2::{Digest, Sha256};
+
+        let msg = b"To really appreciate architecture, you may even need to commit a murder";
+        let s = Scalar::hash_from_bytes_256::<Sha256>(msg);
+        assert_eq!(s, Scalar::hash_from_bytes_256::<Sha256>(msg));
+        assert_ne!(s, Scalar::hash_from_bytes_256::<Sha256>(b"another message"));
+
+        // It differs from reducing a single 256-bit digest.
+        let mut naive = [0u8; 32];
+        naive.copy_from_slice(&Sha256::digest(msg));
+        assert_ne!(s, Scalar::from_bytes_mod_order(naive));
+    }
+
+    #[test]
+    fn from_wide_bytes_takes_digest_output() {
+        use sha2::{Digest, Sha512};
+
+        let msg = b"To really appreciate architecture, you may even need to commit a murder";
+        let digest = Sha512::digest(msg);
+
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&digest);
+
+        let s = Scalar::from_wide_bytes(digest);
+        assert_eq!(s, Scalar::from_bytes_mod_order_wide(&wide));
+        assert_eq!(s, Scalar::hash_from_bytes::<Sha512>(msg));
+    }
+
+    #[test]
+    fn inner_product() {
+        let a = [X, Y, XINV];
+        let b = [Y, Scalar::from(3u64), X];
+        let expected = a.iter().zip(b.iter()).fold(Scalar::zero(), |acc, (a_i, b_i)| acc + a_i * b_i);
+        assert_eq!(Scalar::inner_product(&a, &b), expected);
+
+        // Unreduced inputs are handled.
+        let unreduced = Scalar::from_bits([0xff; 32]);
+        assert_eq!(Scalar::inner_product(&[unreduced], &[unreduced]), unreduced * unreduced);
+
+        assert_eq!(Scalar::inner_product(&[], &[]), Scalar::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn inner_product_length_mismatch_panics() {
+        Scalar::inner_product(&[X, Y], &[X]);
+    }
+
+    #[test]
+    fn powers() {
+        assert!(Scalar::powers(&X, 0).is_empty());
+        assert_eq!(Scalar::powers(&X, 1), vec![Scalar::one()]);
+        assert_eq!(Scalar::powers(&X, 4), vec![Scalar::one(), X, X * X, X * X * X]);
+    }
+
+    #[test]
+    fn horner_eval() {
+        // 1 + 2x + 3x^2 at x = 2
+        let coeffs = [Scalar::from(3u64), Scalar::from(2u64), Scalar::from(1u64)];
+        let y = Scalar::horner_eval(&coeffs, &Scalar::from(2u64));
+        assert_eq!(y, Scalar::from(17u64));
+
+        assert_eq!(Scalar::horner_eval(&[], &X), Scalar::zero());
+
+        let expected = &(&(&X * &X) * &Y) + &(&X * &XINV) + Y;
+        assert_eq!(Scalar::horner_eval(&[Y, XINV, Y], &X), expected);
+    }
+
+    #[test]
+    fn lagrange_coefficient_reconstructs_secret() {
+        // Shares of a degree-2 polynomial with constant term X.
+        let coeffs = [Y, XINV, X];
+        let x_coords: Vec<Scalar> = (1..=5u64).map(Scalar::from).collect();
+        let shares: Vec<Scalar> = x_coords.iter().map(|x| Scalar::horner_eval(&coeffs, x)).collect();
+
+        // Any three shares suffice.
+        for subset in &[[0, 1, 2], [1, 3, 4], [4, 0, 2]] {
+            let xs: Vec<Scalar> = subset.iter().map(|&k| x_coords[k]).collect();
+            let secret: Scalar = subset
+                .iter()
+                .map(|&k| Scalar::lagrange_coefficient(&x_coords[k], &xs) * shares[k])
+                .sum();
+            assert_eq!(secret, X);
+        }
+
+        // With a single point, the constant polynomial is recovered.
+        assert_eq!(Scalar::lagrange_coefficient(&X, &[X]), Scalar::one());
+    }
+
+    #[test]
+    #[should_panic]
+    fn lagrange_coefficient_missing_x_i_panics() {
+        Scalar::lagrange_coefficient(&X, &[Y, XINV]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn lagrange_coefficient_repeated_x_i_panics() {
+        Scalar::lagrange_coefficient(&X, &[X, Y, X]);
+    }
+
+    #[test]
+    fn bits_be_is_reversed_bits() {
+        let le = X.bits();
+        let be = X.bits_be();
+        for i in 0..256 {
+            assert_eq!(be[i], le[255 - i]);
+        }
+        // The top bit of a reduced scalar is clear.
+        assert_eq!(be[0], 0);
+        assert_eq!(Scalar::one().bits_be()[255], 1);
+    }
+
+    #[test]
+    fn batch_invert_to_consistency() {
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<Scalar> = (0..16).map(|_| Scalar::random(&mut rng)).collect();
+        let copy = inputs.clone();
+
+        let inverses = Scalar::batch_invert_to(&inputs);
+
+        assert_eq!(inputs, copy);
+        assert_eq!(inverses.len(), inputs.len());
+        for (inv, x) in inverses.iter().zip(inputs.iter()) {
+            assert_eq!(*inv, x.invert());
+        }
+        assert!(Scalar::batch_invert_to(&[]).is_empty());
+    }
+
+    #[test]
+    fn from_radix_16_roundtrips() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(Scalar::from_radix_16(&s.to_radix_16()), s);
+        }
+
+        // The largest unreduced scalar, 2^255 - 1, round-trips mod l.
+        let big = Scalar::from_bits([0xff; 32]);
+        assert_eq!(Scalar::from_radix_16(&big.to_radix_16()), big.reduce());
+    }
+
     fn test_pippenger_radix_iter(scalar: Scalar, w: usize) {
         let digits_count = Scalar::to_radix_2w_size_hint(w);
         let digits = scalar.to_radix_2w(w);
@@ -1735,6 +3952,46 @@ mod test {
         assert_eq!(recovered_scalar, scalar.reduce());
     }
 
+    #[test]
+    fn montgomery_scalar_matches_mul() {
+        let x = X.to_montgomery();
+        let y = Y.to_montgomery();
+
+        assert_eq!(Scalar::from_montgomery(&(&x * &y)), &X * &Y);
+        assert_eq!(Scalar::from_montgomery(&x.square()), &X * &X);
+        assert_eq!(Scalar::from_montgomery(&(x * x.square() * y)), &(&X * &X) * &(&X * &Y));
+        assert_eq!(Scalar::from_montgomery(&X.to_montgomery()), X);
+    }
+
+    #[test]
+    fn radix_2w_digits_reconstruct() {
+        let mut rng = rand::thread_rng();
+        let cases = (0..20)
+            .map(|_| Scalar::random(&mut rng))
+            .chain(Some(Scalar::from_bits([0xff; 32])));
+
+        for scalar in cases {
+            assert!(scalar.radix_2w_digits(4).eq(scalar.to_radix_16().iter().cloned()));
+
+            for w in 4..=8 {
+                let digits: Vec<i8> = scalar.radix_2w_digits(w).collect();
+                assert_eq!(digits.len(), Scalar::to_radix_2w_size_hint(w));
+
+                let radix = Scalar::from(1u64 << w);
+                let recovered = digits.iter().rev().fold(Scalar::zero(), |acc, &d| {
+                    let d_abs = Scalar::from((d as i64).abs() as u64);
+                    acc * radix + if d < 0 { -d_abs } else { d_abs }
+                });
+                assert_eq!(recovered, scalar.reduce());
+
+                if w > 4 {
+                    let fixed = scalar.to_radix_2w(w);
+                    assert_eq!(&digits[..], &fixed[..digits.len()]);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_pippenger_radix() {
         use core::iter;
@@ -1751,4 +4008,61 @@ mod test {
             test_pippenger_radix_iter(scalar, 8);
         }
     }
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn ff_prime_field_constants() {
+        use group::ff::{Field, PrimeField};
+
+        let g = Scalar::MULTIPLICATIVE_GENERATOR;
+        let four = Scalar::from(4u64);
+        // l - 1 = 2^S * t, and ROOT_OF_UNITY = g^t has order exactly 2^S.
+        let t = (-Scalar::one()) * four.invert();
+        let t_bytes = t.to_bytes();
+        let mut t_limbs = [0u64; 4];
+        for (i, limb) in t_limbs.iter_mut().enumerate() {
+            for j in 0..8 {
+                *limb |= (t_bytes[8 * i + j] as u64) << (8 * j);
+            }
+        }
+        assert_eq!(Scalar::ROOT_OF_UNITY, g.pow_vartime(t_limbs));
+        assert_eq!(Scalar::ROOT_OF_UNITY.pow_vartime([4u64]), Scalar::one());
+        assert_ne!(Scalar::ROOT_OF_UNITY.pow_vartime([2u64]), Scalar::one());
+        assert_eq!(Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV, Scalar::one());
+        assert_eq!(Scalar::DELTA, g.pow_vartime([1u64 << Scalar::S]));
+        assert_eq!(Scalar::TWO_INV.double(), Scalar::one());
+        // g is a non-residue, as required for a multiplicative generator.
+        assert!(bool::from(<Scalar as Field>::sqrt(&g).is_none()));
+    }
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn ff_field_and_repr() {
+        use group::ff::{Field, PrimeField};
+
+        assert!(bool::from(<Scalar as Field>::invert(&Scalar::zero()).is_none()));
+        assert_eq!(<Scalar as Field>::invert(&X).unwrap(), XINV);
+
+        let x2 = <Scalar as Field>::square(&X);
+        let root = <Scalar as Field>::sqrt(&x2).unwrap();
+        assert!(root == X || root == -X);
+
+        assert_eq!(Scalar::from_repr(X.to_repr()).unwrap(), X);
+        assert!(bool::from(Scalar::from_repr(constants::BASEPOINT_ORDER.to_bytes()).is_none()));
+        assert!(bool::from(Scalar::one().is_odd()));
+        assert!(bool::from(Scalar::from(2u64).is_even()));
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn proptest_field_laws(a: Scalar, b: Scalar, c: Scalar) {
+            proptest::prop_assert_eq!((a + b) + c, a + (b + c));
+            proptest::prop_assert_eq!((a * b) * c, a * (b * c));
+            proptest::prop_assert_eq!(a * (b + c), a * b + a * c);
+            proptest::prop_assert_eq!(a + b, b + a);
+            proptest::prop_assert_eq!(a * b, b * a);
+            proptest::prop_assert_eq!(a - a, Scalar::zero());
+        }
+    }
 }